@@ -0,0 +1,336 @@
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::punctuated::Punctuated;
+use syn::{
+    Attribute, Data, DeriveInput, Error, Fields, Ident, ItemFn, LitStr, Token, parse_macro_input,
+};
+
+type Args = Punctuated<Ident, Token![,]>;
+
+fn numbered(ident: &Ident, prefix: &str) -> bool {
+    ident
+        .to_string()
+        .strip_prefix(prefix)
+        .is_some_and(|n| n.parse::<u8>().is_ok_and(|n| n > 0))
+}
+
+fn check_args(args: &Args, is_solution: bool) -> Result<(), Error> {
+    let mut iter = args.iter();
+
+    match iter.next() {
+        Some(day) if numbered(day, "day") => {}
+        Some(other) => return Err(Error::new(other.span(), "expected `dayN`")),
+        None => return Err(Error::new(Span::call_site(), "expected `dayN`")),
+    }
+
+    if is_solution {
+        match iter.next() {
+            Some(part) if numbered(part, "part") => {}
+            Some(other) => return Err(Error::new(other.span(), "expected `partN`")),
+            None => return Err(Error::new(Span::call_site(), "expected `partN`")),
+        }
+
+        // Optional alternate implementation name, ie `Chars`, which may be
+        // marked as a benchmark baseline
+        if iter.next().is_some() {
+            match iter.next() {
+                Some(flag) if flag == "baseline" => {}
+                Some(other) => return Err(Error::new(other.span(), "expected `baseline`")),
+                None => {}
+            }
+        }
+    }
+
+    match iter.next() {
+        Some(extra) => Err(Error::new(extra.span(), "unexpected argument")),
+        None => Ok(()),
+    }
+}
+
+fn marker(args: TokenStream, input: TokenStream, is_solution: bool) -> TokenStream {
+    let args = parse_macro_input!(args with Args::parse_terminated);
+    let item = parse_macro_input!(input as ItemFn);
+
+    if let Err(e) = check_args(&args, is_solution) {
+        return e.to_compile_error().into();
+    }
+
+    quote!(#item).into()
+}
+
+/// Flags a function as a solution: `#[aoc(day1, part1)]`, or with an alternate
+/// implementation name: `#[aoc(day7, part1, Chars)]`.
+///
+/// An alternate implementation kept only to time others against is marked
+/// `#[aoc(day15, part1, Pairwise, baseline)]`. Only `aoc bench` and `aoc list`
+/// include it.
+///
+/// The function itself is left untouched. The crate's build script reads these
+/// attributes to register the solution under the year of its parent module.
+#[proc_macro_attribute]
+pub fn aoc(args: TokenStream, input: TokenStream) -> TokenStream {
+    marker(args, input, true)
+}
+
+/// Flags a function as the input generator for a day: `#[aoc_generator(day4)]`.
+///
+/// Every solution of that day is then passed a reference to the generated value
+/// instead of the raw input.
+#[proc_macro_attribute]
+pub fn aoc_generator(args: TokenStream, input: TokenStream) -> TokenStream {
+    marker(args, input, false)
+}
+
+/// A piece of a `#[pattern(...)]`
+enum Segment {
+    Literal(String),
+    /// A `{field}` placeholder
+    Field(String),
+}
+
+fn parse_pattern(pattern: &LitStr) -> Result<Vec<Segment>, Error> {
+    let text = pattern.value();
+    let error = |message: &str| Error::new(pattern.span(), message);
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let name: String = chars.by_ref().take_while(|&ch| ch != '}').collect();
+
+                if name.is_empty() {
+                    return Err(error("placeholders need a field name, ie `{start}`"));
+                }
+
+                if literal.is_empty() && matches!(segments.last(), Some(Segment::Field(_))) {
+                    return Err(error("placeholders must be separated by some text"));
+                }
+
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                }
+
+                segments.push(Segment::Field(name));
+            }
+            '}' => return Err(error("unmatched `}`, use `}}` for a literal one")),
+            _ => literal.push(ch),
+        }
+    }
+
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+
+    Ok(segments)
+}
+
+fn pattern_of(attrs: &[Attribute], span: Span) -> Result<LitStr, Error> {
+    let mut patterns = attrs.iter().filter(|attr| attr.path().is_ident("pattern"));
+    let pattern = patterns
+        .next()
+        .ok_or_else(|| Error::new(span, "expected a `#[pattern(\"...\")]` attribute"))?;
+
+    if let Some(extra) = patterns.next() {
+        return Err(Error::new_spanned(extra, "only one pattern is allowed"));
+    }
+
+    pattern.parse_args()
+}
+
+/// Builds `path { field: ... }` from a line matching `pattern`. `source` and
+/// `line` are in scope in the generated code.
+fn parse_fields(
+    path: TokenStream2,
+    fields: &Fields,
+    pattern: &LitStr,
+) -> Result<TokenStream2, Error> {
+    let segments = parse_pattern(pattern)?;
+    let names: Vec<String> = match fields {
+        Fields::Named(named) => named
+            .named
+            .iter()
+            .map(|field| field.ident.as_ref().unwrap().to_string())
+            .collect(),
+        Fields::Unnamed(unnamed) => (0..unnamed.unnamed.len()).map(|i| i.to_string()).collect(),
+        Fields::Unit => Vec::new(),
+    };
+    let mut found = vec![None; names.len()];
+    let mut steps = Vec::new();
+
+    for (i, segment) in segments.iter().enumerate() {
+        match segment {
+            Segment::Literal(literal) if i == 0 => steps.push(quote! {
+                let rest = source.strip_prefix(rest, #literal)?;
+            }),
+            // Already consumed by the field before it
+            Segment::Literal(_) => {}
+            Segment::Field(name) => {
+                let index = names.iter().position(|n| n == name).ok_or_else(|| {
+                    Error::new(pattern.span(), format!("no field named `{}`", name))
+                })?;
+
+                if found[index].is_some() {
+                    return Err(Error::new(
+                        pattern.span(),
+                        format!("`{}` appears more than once", name),
+                    ));
+                }
+
+                let var = format_ident!("field_{}", index);
+
+                steps.push(match segments.get(i + 1) {
+                    // The text up to the first occurrence of the next literal,
+                    // or up to the final one if that ends the pattern
+                    Some(Segment::Literal(literal)) if i + 2 == segments.len() => quote! {
+                        let (#var, rest) = (source.strip_suffix(rest, #literal)?, "");
+                    },
+                    Some(Segment::Literal(literal)) => quote! {
+                        let (#var, rest) = source.split_once(rest, #literal)?;
+                    },
+                    _ => quote! {
+                        let (#var, rest) = (rest, "");
+                    },
+                });
+                found[index] = Some(var);
+            }
+        }
+    }
+
+    if let Some(missing) = found.iter().position(Option::is_none) {
+        return Err(Error::new(
+            pattern.span(),
+            format!("the pattern has no `{{{}}}` placeholder", names[missing]),
+        ));
+    }
+
+    let vars = found.into_iter().flatten();
+    let construct = match fields {
+        Fields::Named(named) => {
+            let idents = named.named.iter().map(|field| &field.ident);
+
+            quote!(#path { #(#idents: source.parse(#vars)?),* })
+        }
+        Fields::Unnamed(_) => quote!(#path(#(source.parse(#vars)?),*)),
+        Fields::Unit => quote!(#path),
+    };
+
+    Ok(quote! {{
+        let rest = line;
+        #(#steps)*
+        source.end(rest)?;
+
+        Ok(#construct)
+    }})
+}
+
+fn from_line(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let pattern = pattern_of(&input.attrs, input.ident.span())?;
+
+            parse_fields(quote!(Self), &data.fields, &pattern)?
+        }
+        Data::Enum(data) => {
+            let mut patterns = Vec::new();
+            let mut arms = Vec::new();
+
+            for variant in &data.variants {
+                let ident = &variant.ident;
+                let pattern = pattern_of(&variant.attrs, ident.span())?;
+                let parse = parse_fields(quote!(Self::#ident), &variant.fields, &pattern)?;
+                let prefix = match parse_pattern(&pattern)?.into_iter().next() {
+                    Some(Segment::Literal(literal)) => literal,
+                    _ => String::new(),
+                };
+
+                arms.push(quote! {
+                    if line.starts_with(#prefix) {
+                        return #parse;
+                    }
+                });
+                patterns.push(format!("`{}`", pattern.value()));
+            }
+
+            let expected = match patterns.split_last() {
+                Some((last, [])) => format!("expected {}", last),
+                Some((last, rest)) => format!("expected {} or {}", rest.join(", "), last),
+                None => return Err(Error::new(input.ident.span(), "expected some variants")),
+            };
+
+            quote! {
+                #(#arms)*
+
+                Err(source.error(line, #expected))
+            }
+        }
+        Data::Union(_) => return Err(Error::new(input.ident.span(), "unions are not supported")),
+    };
+    let ident = &input.ident;
+
+    Ok(quote! {
+        impl crate::parse::FromLine for #ident {
+            fn from_line<'a>(
+                source: &crate::parse::Source<'a>,
+                line: &'a str,
+            ) -> Result<Self, crate::parse::ParseError> {
+                #body
+            }
+        }
+
+        impl std::str::FromStr for #ident {
+            type Err = crate::parse::ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                <Self as crate::parse::FromLine>::from_line(&crate::parse::Source::new(s), s)
+            }
+        }
+    })
+}
+
+/// Parses a line of input by a pattern of literal text and `{field}`
+/// placeholders, generating `FromLine` and `FromStr` impls:
+///
+/// ```ignore
+/// #[derive(FromLine)]
+/// #[pattern("{start}-{end}")]
+/// struct Range {
+///     start: u64,
+///     end: u64,
+/// }
+/// ```
+///
+/// Each placeholder takes the text up to the literal after it, and is parsed
+/// with its field's `FromStr`. Tuple fields are named by index, ie `{0}`, and
+/// `{{`/`}}` are literal braces. Each enum variant has its own pattern, and
+/// the first one whose leading text the line starts with is used:
+///
+/// ```ignore
+/// #[derive(FromLine)]
+/// enum Instruction {
+///     #[pattern("noop")]
+///     Noop,
+///     #[pattern("addx {0}")]
+///     Addx(i32),
+/// }
+/// ```
+///
+/// Only usable inside this crate, as the impls refer to `crate::parse`.
+#[proc_macro_derive(FromLine, attributes(pattern))]
+pub fn derive_from_line(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    from_line(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,285 @@
+//! Collects every `#[aoc]` solution under `src/yYYYY/dayN.rs` into a registry
+//! table that `src/registry.rs` includes, and generates one test per example
+//! under `examples/<year>/dayN/<name>.txt` for `tests/examples.rs`.
+//!
+//! Only years whose `yYYYY` Cargo feature is enabled are included.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+struct Entry {
+    year: u16,
+    module: String,
+    day: u8,
+    part: u8,
+    name: Option<String>,
+    baseline: bool,
+    func: String,
+    fallible: bool,
+}
+
+struct Day {
+    year: u16,
+    module: String,
+    /// Generator name and whether it returns a `Result`
+    generator: Option<(String, bool)>,
+    solutions: Vec<Entry>,
+}
+
+enum Attr {
+    Generator,
+    Solution {
+        day: u8,
+        part: u8,
+        name: Option<String>,
+        baseline: bool,
+    },
+}
+
+fn parse_attr(line: &str) -> Option<Attr> {
+    if let Some(args) = line.strip_prefix("#[aoc_generator(") {
+        args.strip_suffix(")]")?;
+
+        return Some(Attr::Generator);
+    }
+
+    let args = line.strip_prefix("#[aoc(")?.strip_suffix(")]")?;
+    let mut args = args.split(',').map(str::trim);
+    let day = args.next()?.strip_prefix("day")?.parse().ok()?;
+    let part = args.next()?.strip_prefix("part")?.parse().ok()?;
+    let name = args.next().map(str::to_owned);
+    let baseline = args.next() == Some("baseline");
+
+    Some(Attr::Solution {
+        day,
+        part,
+        name,
+        baseline,
+    })
+}
+
+fn fn_name(line: &str) -> Option<String> {
+    let rest = line.strip_prefix("pub fn ")?;
+    let end = rest.find(['(', '<'])?;
+
+    Some(rest[..end].to_owned())
+}
+
+fn parse_day(year: u16, module: String, source: &str) -> Day {
+    let mut day = Day {
+        year,
+        module,
+        generator: None,
+        solutions: Vec::new(),
+    };
+    let mut pending = None;
+    let mut lines = source.lines().map(str::trim);
+
+    while let Some(line) = lines.next() {
+        if let Some(attr) = parse_attr(line) {
+            pending = Some(attr);
+            continue;
+        }
+
+        // Other attributes and doc comments may sit between the marker and the fn
+        if line.starts_with("#[") || line.starts_with("///") {
+            continue;
+        }
+
+        let Some(attr) = pending.take() else {
+            continue;
+        };
+        let func = fn_name(line).unwrap_or_else(|| {
+            panic!("{}::{}: #[aoc] must be followed by a `pub fn`", year, day.module)
+        });
+        let mut signature = line.to_owned();
+
+        // rustfmt may spread the signature over several lines
+        while !signature.contains('{') {
+            match lines.next() {
+                Some(line) => signature.push_str(line),
+                None => break,
+            }
+        }
+
+        let fallible = signature.contains("-> Result<");
+
+        match attr {
+            Attr::Generator => day.generator = Some((func, fallible)),
+            Attr::Solution {
+                day: day_num,
+                part,
+                name,
+                baseline,
+            } => day.solutions.push(Entry {
+                year,
+                module: day.module.clone(),
+                day: day_num,
+                part,
+                name,
+                baseline,
+                func,
+                fallible,
+            }),
+        }
+    }
+
+    day
+}
+
+/// Whether the `yYYYY` feature gating a year's modules is enabled.
+fn year_enabled(year: u16) -> bool {
+    env::var_os(format!("CARGO_FEATURE_Y{}", year)).is_some()
+}
+
+fn example_tests() -> String {
+    let mut examples = Vec::new();
+
+    println!("cargo::rerun-if-changed=examples");
+
+    let Ok(year_dirs) = fs::read_dir("examples") else {
+        return String::new();
+    };
+
+    for year_dir in year_dirs {
+        let year_dir = year_dir.unwrap().path();
+        let Some(year) = year_dir
+            .file_name()
+            .and_then(|name| name.to_str()?.parse::<u16>().ok())
+            .filter(|&year| year_enabled(year))
+        else {
+            continue;
+        };
+
+        for day_dir in fs::read_dir(&year_dir).unwrap() {
+            let day_dir = day_dir.unwrap().path();
+            let Some(day) = day_dir
+                .file_name()
+                .and_then(|name| name.to_str()?.strip_prefix("day")?.parse::<u8>().ok())
+            else {
+                continue;
+            };
+
+            for file in fs::read_dir(&day_dir).unwrap() {
+                let file = file.unwrap().path();
+
+                if file.extension().is_none_or(|ext| ext != "txt") {
+                    continue;
+                }
+
+                let name = file.file_stem().unwrap().to_str().unwrap().to_owned();
+
+                examples.push((year, day, name));
+            }
+        }
+    }
+
+    examples.sort();
+
+    let mut out = String::new();
+
+    for (year, day, name) in examples {
+        let ident: String = name
+            .chars()
+            .map(|ch| if ch.is_ascii_alphanumeric() { ch.to_ascii_lowercase() } else { '_' })
+            .collect();
+
+        writeln!(
+            out,
+            "#[test]\nfn y{year}_day{day}_{ident}() {{\n    aoc::examples::check({year}, {day}, {name:?});\n}}\n"
+        )
+        .unwrap();
+    }
+
+    out
+}
+
+fn main() {
+    let src = Path::new("src");
+    let mut days = Vec::new();
+
+    println!("cargo::rerun-if-changed=src");
+
+    for year_dir in fs::read_dir(src).unwrap() {
+        let year_dir = year_dir.unwrap().path();
+        let Some(year) = year_dir
+            .file_name()
+            .and_then(|name| name.to_str()?.strip_prefix('y')?.parse::<u16>().ok())
+            .filter(|&year| year_enabled(year))
+        else {
+            continue;
+        };
+
+        for file in fs::read_dir(&year_dir).unwrap() {
+            let file = file.unwrap().path();
+            let Some(module) = file.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+
+            if !module.starts_with("day") {
+                continue;
+            }
+
+            let source = fs::read_to_string(&file).unwrap();
+
+            days.push(parse_day(year, module.to_owned(), &source));
+        }
+    }
+
+    let mut entries = Vec::new();
+
+    for day in &days {
+        for entry in &day.solutions {
+            let path = format!("crate::y{}::{}", day.year, day.module);
+            let try_solve = if entry.fallible { "?" } else { "" };
+            let (generate, solve) = match &day.generator {
+                Some((generator, fallible)) => (
+                    format!(
+                        "{path}::{generator}(input){}",
+                        if *fallible { "?" } else { "" }
+                    ),
+                    format!("{path}::{}(input.downcast_ref().unwrap()){try_solve}", entry.func),
+                ),
+                None => (
+                    "input.to_owned()".to_owned(),
+                    format!(
+                        "{path}::{}(input.downcast_ref::<String>().unwrap()){try_solve}",
+                        entry.func
+                    ),
+                ),
+            };
+
+            entries.push((entry, generate, solve));
+        }
+    }
+
+    entries.sort_by(|(lhs, ..), (rhs, ..)| {
+        (lhs.year, lhs.day, lhs.part, &lhs.name, &lhs.module).cmp(&(
+            rhs.year,
+            rhs.day,
+            rhs.part,
+            &rhs.name,
+            &rhs.module,
+        ))
+    });
+
+    let mut out = String::from("&[\n");
+
+    for (entry, generate, solve) in entries {
+        writeln!(
+            out,
+            "    Solution {{ year: {}, day: {}, part: {}, name: {:?}, baseline: {}, generate: |input| Ok(Box::new({generate}) as Generated), solve: |input| Ok(Answer::from({solve})) }},",
+            entry.year, entry.day, entry.part, entry.name, entry.baseline,
+        )
+        .unwrap();
+    }
+
+    out.push(']');
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+
+    fs::write(Path::new(&out_dir).join("solutions.rs"), out).unwrap();
+    fs::write(Path::new(&out_dir).join("examples.rs"), example_tests()).unwrap();
+}
@@ -0,0 +1,201 @@
+use std::fmt::{self, Display, Formatter};
+use std::hash::{Hash, Hasher};
+
+use serde::ser::{Serialize, SerializeSeq, Serializer};
+use toml::Value;
+
+/// The result of a solution, in whichever shape the puzzle asks for.
+///
+/// Answers are compared by their normalized `Display` form, which is what
+/// would be submitted: `Int(21)` equals `String("21")`, and a tuple of
+/// `(21, 53)` equals `String("21,53")`.
+#[derive(Clone, Debug)]
+pub enum Answer {
+    Int(i128),
+    String(String),
+    /// Several values, displayed comma separated, ie coordinates
+    Tuple(Vec<Answer>),
+    /// Rows of lit pixels, ie letters drawn on a screen
+    Bitmap(Vec<Vec<bool>>),
+}
+
+impl Answer {
+    /// Whether the answer spans several lines when displayed.
+    pub fn is_multiline(&self) -> bool {
+        match self {
+            Answer::String(s) => s.trim().contains('\n'),
+            Answer::Bitmap(rows) => rows.len() > 1,
+            Answer::Int(_) | Answer::Tuple(_) => false,
+        }
+    }
+
+    /// Converts an answer stored in a TOML file. Arrays become tuples.
+    pub fn from_toml(value: Value) -> Result<Self, String> {
+        match value {
+            Value::Integer(i) => Ok(Answer::Int(i.into())),
+            Value::String(s) => Ok(Answer::String(s)),
+            Value::Array(values) => values
+                .into_iter()
+                .map(Answer::from_toml)
+                .collect::<Result<_, _>>()
+                .map(Answer::Tuple),
+            other => Err(format!("unsupported answer {}", other)),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(i) => write!(f, "{}", i),
+            Answer::String(s) => write!(f, "{}", s.trim()),
+            Answer::Tuple(values) => {
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+
+                    write!(f, "{}", value)?;
+                }
+
+                Ok(())
+            }
+            Answer::Bitmap(rows) => {
+                for (i, row) in rows.iter().enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+
+                    for &lit in row {
+                        write!(f, "{}", if lit { '#' } else { '.' })?;
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+impl Eq for Answer {}
+
+impl Hash for Answer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_string().hash(state);
+    }
+}
+
+/// Integers, strings and tuples serialize as themselves, bitmaps as a list of
+/// `#`/`.` rows.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Int(i) => serializer.serialize_i128(*i),
+            Answer::String(s) => serializer.serialize_str(s.trim()),
+            Answer::Tuple(values) => values.serialize(serializer),
+            Answer::Bitmap(_) => {
+                let rendered = self.to_string();
+                let mut seq = serializer.serialize_seq(None)?;
+
+                for row in rendered.lines() {
+                    seq.serialize_element(row)?;
+                }
+
+                seq.end()
+            }
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(i: $int) -> Self {
+                    Answer::Int(i as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, i128);
+
+impl From<u128> for Answer {
+    fn from(i: u128) -> Self {
+        // Too big to be an Int, but still a number
+        i128::try_from(i).map_or_else(|_| Answer::String(i.to_string()), Answer::Int)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::String(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::String(s.to_owned())
+    }
+}
+
+impl<A: Into<Answer>, B: Into<Answer>> From<(A, B)> for Answer {
+    fn from((a, b): (A, B)) -> Self {
+        Answer::Tuple(vec![a.into(), b.into()])
+    }
+}
+
+impl<A: Into<Answer>, B: Into<Answer>, C: Into<Answer>> From<(A, B, C)> for Answer {
+    fn from((a, b, c): (A, B, C)) -> Self {
+        Answer::Tuple(vec![a.into(), b.into(), c.into()])
+    }
+}
+
+#[test]
+fn test_answer_display() {
+    let bitmap = Answer::Bitmap(vec![vec![true, false, true], vec![false, true, false]]);
+
+    assert_eq!(Answer::from(-3i16).to_string(), "-3");
+    assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+    assert_eq!(Answer::from(u128::MAX), Answer::from(u128::MAX.to_string()));
+    assert_eq!(Answer::from("\nabc\n").to_string(), "abc");
+    assert_eq!(Answer::from((233usize, 250usize, 12usize)).to_string(), "233,250,12");
+    assert_eq!(bitmap.to_string(), "#.#\n.#.");
+    assert!(bitmap.is_multiline());
+    assert!(!Answer::from((1, 2)).is_multiline());
+}
+
+#[test]
+fn test_answer_eq() {
+    let bitmap = Answer::Bitmap(vec![vec![true, false], vec![false, true]]);
+
+    assert_eq!(Answer::from(12560), Answer::from("12560"));
+    assert_eq!(Answer::from((21, 53)), Answer::from("21,53"));
+    assert_eq!(bitmap, Answer::from("\n#.\n.#\n"));
+    assert_ne!(Answer::from("0012"), Answer::from(12));
+    assert_eq!(
+        Answer::from_toml(toml::toml! { a = [21, 53] }["a"].clone()),
+        Ok(Answer::from((21, 53)))
+    );
+    assert!(Answer::from_toml(Value::Float(1.5)).is_err());
+}
+
+#[test]
+fn test_answer_serialize() {
+    let json = |answer: Answer| serde_json::to_string(&answer).unwrap();
+
+    assert_eq!(json(Answer::from(7u64)), "7");
+    assert_eq!(json(Answer::from("\nPLPAFBCL")), "\"PLPAFBCL\"");
+    assert_eq!(json(Answer::from((1, "a"))), "[1,\"a\"]");
+    assert_eq!(
+        json(Answer::Bitmap(vec![vec![true, false], vec![false, true]])),
+        "[\"#.\",\".#\"]"
+    );
+}
@@ -0,0 +1,175 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use toml::Value;
+
+use crate::answer::Answer;
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io { path: PathBuf, source: io::Error },
+    Parse { path: PathBuf, message: String },
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            AnswersError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            AnswersError::Parse { path, message } => write!(f, "{}: {}", path.display(), message),
+        }
+    }
+}
+
+impl Error for AnswersError {}
+
+/// Outcome of comparing a solution's output against the known answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer },
+    Missing,
+}
+
+/// Known-correct answers for a single year, keyed by (day, part).
+///
+/// Stored as `answers/<year>.toml`:
+///
+/// ```toml
+/// [day7]
+/// part1 = 2104783
+/// part2 = "PLPAFBCL"
+/// ```
+///
+/// Only record answers the puzzle site accepted or that were worked out
+/// independently. Copying the output of the code being checked means
+/// `aoc check` will keep passing that code even when it's wrong.
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), Answer>,
+}
+
+/// Where the answers for a given year are stored: `answers/<year>.toml`
+pub fn path(year: u16) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("answers")
+        .join(format!("{}.toml", year))
+}
+
+fn numbered(key: &str, prefix: &str) -> Option<u8> {
+    key.strip_prefix(prefix)?.parse().ok()
+}
+
+/// Parses a `partN = answer` table. Answers may be integers, strings or arrays.
+pub(crate) fn parse_parts(parts: BTreeMap<String, Value>) -> Result<BTreeMap<u8, Answer>, String> {
+    let mut answers = BTreeMap::new();
+
+    for (key, value) in parts {
+        let part = numbered(&key, "part").ok_or_else(|| format!("invalid part: {}", key))?;
+        let answer = Answer::from_toml(value).map_err(|e| format!("{}: {}", key, e))?;
+
+        answers.insert(part, answer);
+    }
+
+    Ok(answers)
+}
+
+impl Answers {
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let table: BTreeMap<String, BTreeMap<String, Value>> =
+            toml::from_str(source).map_err(|e| e.message().to_owned())?;
+        let mut answers = BTreeMap::new();
+
+        for (day_key, parts) in table {
+            let day = numbered(&day_key, "day").ok_or_else(|| format!("invalid day: {}", day_key))?;
+            let parts = parse_parts(parts).map_err(|e| format!("{}: {}", day_key, e))?;
+
+            for (part, answer) in parts {
+                answers.insert((day, part), answer);
+            }
+        }
+
+        Ok(Answers { answers })
+    }
+
+    /// Loads `answers/<year>.toml`. A year without a file has no known answers.
+    pub fn load(year: u16) -> Result<Self, AnswersError> {
+        let path = path(year);
+        let source = match fs::read_to_string(&path) {
+            Ok(source) => source,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(source) => return Err(AnswersError::Io { path, source }),
+        };
+
+        Answers::parse(&source).map_err(|message| AnswersError::Parse { path, message })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+
+    /// Compares an answer against the known one.
+    pub fn check(&self, day: u8, part: u8, actual: &Answer) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Missing,
+            Some(expected) if expected == actual => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
+
+#[test]
+fn test_answers_parse() {
+    let answers = Answers::parse(
+        r#"
+[day7]
+part1 = 2104783
+part2 = "abc"
+
+[day11]
+part1 = [21, 53]
+
+[day10]
+part2 = """
+#..#
+####
+"""
+"#,
+    )
+    .unwrap();
+
+    let bitmap = Answer::Bitmap(vec![
+        vec![true, false, false, true],
+        vec![true, true, true, true],
+    ]);
+
+    assert_eq!(answers.get(7, 1), Some(&Answer::Int(2104783)));
+    assert_eq!(answers.get(7, 2), Some(&Answer::from("abc")));
+    assert_eq!(answers.get(8, 1), None);
+    assert_eq!(answers.check(7, 1, &Answer::from(2104783u32)), Verdict::Pass);
+    assert_eq!(answers.check(10, 2, &bitmap), Verdict::Pass);
+    assert_eq!(answers.check(11, 1, &Answer::from((21, 53))), Verdict::Pass);
+    assert_eq!(
+        answers.check(7, 2, &Answer::from("abd")),
+        Verdict::Fail {
+            expected: "abc".into()
+        }
+    );
+    assert_eq!(answers.check(9, 1, &Answer::from(1)), Verdict::Missing);
+
+    assert!(Answers::parse("[seven]\npart1 = 1").is_err());
+    assert!(Answers::parse("[day7]\npart1 = 1.5").is_err());
+}
+
+#[test]
+fn test_answers_load() {
+    let answers = Answers::load(2025).unwrap();
+
+    assert_eq!(answers.get(1, 1), Some(&Answer::Int(969)));
+    assert!(Answers::load(1999).unwrap().get(1, 1).is_none());
+}
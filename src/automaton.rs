@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use crate::grid::{Grid, Pos};
+
+/// The up to 8 cells around a cell, in reading order. Cells past the edge of
+/// the grid are left out.
+pub struct Neighbours<'a, T> {
+    cells: Vec<&'a T>,
+}
+
+impl<'a, T> Neighbours<'a, T> {
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.cells.iter().copied()
+    }
+
+    pub fn count(&self, mut f: impl FnMut(&T) -> bool) -> usize {
+        self.cells.iter().filter(|cell| f(cell)).count()
+    }
+}
+
+/// How a run of an automaton ended
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    /// Generation `generation` and every one after it are the same
+    FixedPoint { generation: usize },
+    /// Generation `start + period` is the same as generation `start`
+    Cycle { start: usize, period: usize },
+}
+
+/// Steps a grid of cells through generations, working out each cell's next
+/// state from its current one and its 8 neighbours. Every cell is updated at
+/// once, and after the first generation only cells next to a change are
+/// revisited.
+///
+/// Earlier generations aren't kept whole. Each generation's changes are
+/// recorded instead, so they can be undone to get back any earlier grid, and
+/// repeats are spotted by a fingerprint of the grid that's kept up to date as
+/// cells change.
+pub struct Automaton<T, R> {
+    grid: Grid<T>,
+    rule: R,
+    generation: usize,
+    /// Whether each cell, by flat index, might change in the next generation
+    dirty: Vec<bool>,
+    /// The flat indexes of the dirty cells
+    pending: Vec<usize>,
+    changed: Vec<Pos>,
+    /// The cells each generation changed, by flat index, with their value
+    /// from before
+    undo: Vec<Vec<(usize, T)>>,
+    /// The wrapping sum of every cell's hash
+    fingerprint: u64,
+    /// The generations seen with each fingerprint, for cycle detection
+    seen: HashMap<u64, Vec<usize>>,
+    /// How it ended, once the grid has repeated
+    outcome: Option<Outcome>,
+}
+
+fn cell_hash<T: Hash>(index: usize, cell: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    (index, cell).hash(&mut hasher);
+    hasher.finish()
+}
+
+impl<T, R> Automaton<T, R>
+where
+    T: Clone + Eq + Hash,
+    R: Fn(&T, &Neighbours<T>) -> T,
+{
+    pub fn new(grid: Grid<T>, rule: R) -> Self {
+        let fingerprint = grid
+            .iter()
+            .enumerate()
+            .fold(0, |sum: u64, (index, cell)| sum.wrapping_add(cell_hash(index, cell)));
+
+        Automaton {
+            dirty: vec![true; grid.len()],
+            pending: (0..grid.len()).collect(),
+            undo: Vec::new(),
+            fingerprint,
+            seen: HashMap::from([(fingerprint, vec![0])]),
+            outcome: None,
+            grid,
+            rule,
+            generation: 0,
+            changed: Vec::new(),
+        }
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Moves on to the next generation, returning the cells that changed.
+    pub fn step(&mut self) -> &[Pos] {
+        let mut updates = Vec::new();
+
+        self.pending.sort_unstable();
+
+        for index in self.pending.drain(..) {
+            self.dirty[index] = false;
+
+            let pos = self.grid.pos_of(index);
+            let neighbours = Neighbours {
+                cells: self.grid.neighbours8(pos).map(|pos| &self.grid[pos]).collect(),
+            };
+            let next = (self.rule)(&self.grid[index], &neighbours);
+
+            if next != self.grid[index] {
+                updates.push((index, next));
+            }
+        }
+
+        let mut undo = Vec::with_capacity(updates.len());
+
+        self.changed.clear();
+
+        for (index, next) in updates {
+            self.fingerprint = self
+                .fingerprint
+                .wrapping_sub(cell_hash(index, &self.grid[index]))
+                .wrapping_add(cell_hash(index, &next));
+            undo.push((index, std::mem::replace(&mut self.grid[index], next)));
+            self.changed.push(self.grid.pos_of(index));
+        }
+
+        for &pos in &self.changed {
+            for pos in std::iter::once(pos).chain(self.grid.neighbours8(pos)) {
+                let index = self.grid.index_of(pos);
+
+                if !std::mem::replace(&mut self.dirty[index], true) {
+                    self.pending.push(index);
+                }
+            }
+        }
+
+        self.undo.push(undo);
+        self.generation += 1;
+
+        let seen = self.seen.entry(self.fingerprint).or_default();
+
+        if self.outcome.is_none() {
+            let repeat = seen.iter().copied().find(|&start| {
+                Self::unchanged_since(&self.grid, &self.undo[start..])
+            });
+
+            self.outcome = repeat.map(|start| match self.generation - start {
+                1 => Outcome::FixedPoint { generation: start },
+                period => Outcome::Cycle { start, period },
+            });
+        }
+
+        seen.push(self.generation);
+
+        &self.changed
+    }
+
+    /// Whether undoing `undo` would leave `grid` as it is, ie every cell it
+    /// changed has since gone back to how it was. Fingerprints can collide,
+    /// so this is what confirms a repeat.
+    fn unchanged_since(grid: &Grid<T>, undo: &[Vec<(usize, T)>]) -> bool {
+        let mut before = HashMap::new();
+
+        for (index, old) in undo.iter().flatten() {
+            before.entry(*index).or_insert(old);
+        }
+
+        before.into_iter().all(|(index, old)| grid[index] == *old)
+    }
+
+    /// Steps until the grid repeats an earlier generation, calling
+    /// `on_generation` with each generation that changed anything, its grid
+    /// and the cells that changed. Once it has, later calls return the same
+    /// outcome straight away.
+    pub fn run(&mut self, mut on_generation: impl FnMut(usize, &Grid<T>, &[Pos])) -> Outcome {
+        loop {
+            if let Some(outcome) = self.outcome {
+                return outcome;
+            }
+
+            if !self.step().is_empty() {
+                on_generation(self.generation, &self.grid, &self.changed);
+            }
+        }
+    }
+
+    /// The grid at any generation, extrapolated from a fixed point or cycle
+    /// rather than stepped to when it's far off.
+    pub fn grid_at(&mut self, generation: usize) -> Grid<T> {
+        let generation = if generation <= self.generation {
+            generation
+        } else {
+            match self.run(|_, _, _| ()) {
+                Outcome::FixedPoint { generation: last } => generation.min(last),
+                Outcome::Cycle { start, period } if generation > start => {
+                    start + (generation - start) % period
+                }
+                Outcome::Cycle { .. } => generation,
+            }
+        };
+        let mut grid = self.grid.clone();
+
+        for (index, old) in self.undo[generation..].iter().rev().flatten() {
+            grid[*index] = old.clone();
+        }
+
+        grid
+    }
+}
+
+#[cfg(test)]
+fn life(cell: &bool, neighbours: &Neighbours<bool>) -> bool {
+    matches!((cell, neighbours.count(|&alive| alive)), (true, 2) | (_, 3))
+}
+
+#[cfg(test)]
+fn life_grid(image: &str) -> Grid<bool> {
+    Grid::parse(image, |ch| Some(ch == '#')).unwrap()
+}
+
+#[test]
+fn test_automaton_cycle() {
+    let mut blinker = Automaton::new(life_grid(".....\n..#..\n..#..\n..#..\n....."), life);
+    let mut generations = Vec::new();
+
+    assert_eq!(
+        blinker.run(|generation, _, changed| generations.push((generation, changed.len()))),
+        Outcome::Cycle { start: 0, period: 2 }
+    );
+    assert_eq!(generations, [(1, 4), (2, 4)]);
+    assert_eq!(blinker.grid_at(1_000_001), life_grid(".....\n.....\n.###.\n.....\n....."));
+    assert_eq!(blinker.grid_at(1_000_000), blinker.grid_at(0));
+}
+
+#[test]
+fn test_automaton_fixed_point() {
+    // A block with a stray cell that dies off
+    let start = life_grid("##...\n##...\n.....\n....#");
+    let mut automaton = Automaton::new(start.clone(), life);
+
+    assert_eq!(automaton.step(), &[(4, 3)]);
+    assert_eq!(automaton.generation(), 1);
+    assert_eq!(automaton.run(|_, _, _| ()), Outcome::FixedPoint { generation: 1 });
+
+    let mut automaton = Automaton::new(start, life);
+
+    assert_eq!(automaton.grid_at(50), life_grid("##...\n##...\n.....\n....."));
+}
+
+#[test]
+fn test_automaton_run_again() {
+    let mut block = Automaton::new(life_grid("....\n.##.\n.##.\n...."), life);
+
+    assert_eq!(block.run(|_, _, _| ()), Outcome::FixedPoint { generation: 0 });
+    assert_eq!(block.run(|_, _, _| panic!("already settled")), Outcome::FixedPoint { generation: 0 });
+
+    // A glider that runs into the corner of the grid and settles there
+    let glider = life_grid(".#....\n..#...\n###...\n......\n......\n......");
+    let mut automaton = Automaton::new(glider.clone(), life);
+    let outcome = automaton.run(|_, _, _| ());
+    let generation = automaton.generation();
+
+    assert!(matches!(outcome, Outcome::FixedPoint { .. }));
+    assert_eq!(automaton.run(|_, _, _| ()), outcome);
+    assert_eq!(automaton.generation(), generation);
+
+    // Earlier generations are rebuilt from the changes made since
+    let mut stepped = Automaton::new(glider.clone(), life);
+
+    for generation in 0..=generation {
+        assert_eq!(automaton.grid_at(generation), *stepped.grid());
+        stepped.step();
+    }
+
+    assert_eq!(automaton.grid_at(0), glider);
+}
@@ -0,0 +1,179 @@
+use std::fmt::{self, Display, Formatter};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use serde::{Serialize, Serializer};
+
+use crate::answer::Answer;
+use crate::registry::{Solution, SolveError};
+
+fn as_nanos<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+/// Summary of a set of timing samples. Serialized as nanoseconds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", serialize_with = "as_nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "as_nanos")]
+    pub median: Duration,
+    #[serde(rename = "p95_ns", serialize_with = "as_nanos")]
+    pub p95: Duration,
+    #[serde(rename = "mean_ns", serialize_with = "as_nanos")]
+    pub mean: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "Need at least one sample");
+
+        samples.sort_unstable();
+
+        let len = samples.len();
+        // Nearest-rank percentile
+        let p95_rank = (len * 95).div_ceil(100);
+        let median = if len % 2 == 1 {
+            samples[len / 2]
+        } else {
+            (samples[len / 2 - 1] + samples[len / 2]) / 2
+        };
+
+        Stats {
+            min: samples[0],
+            median,
+            p95: samples[p95_rank - 1],
+            mean: samples.iter().sum::<Duration>() / len as u32,
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "min {:?}, median {:?}, p95 {:?}",
+            self.min, self.median, self.p95
+        )
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    #[serde(rename = "impl")]
+    pub name: Option<&'static str>,
+    pub iterations: usize,
+    pub answer: Answer,
+    pub generator: Stats,
+    pub solve: Stats,
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} Day {} - Part {}", self.year, self.day, self.part)?;
+
+        if let Some(name) = self.name {
+            write!(f, " - {}", name)?;
+        }
+
+        write!(
+            f,
+            " ({} iterations)\n\tgenerator: {}\n\tsolve:     {}",
+            self.iterations, self.generator, self.solve
+        )?;
+
+        if self.answer.is_multiline() {
+            write!(f, "\n\tanswer:\n{}", self.answer)
+        } else {
+            write!(f, "\n\tanswer:    {}", self.answer)
+        }
+    }
+}
+
+fn sample<T>(warmup: usize, iterations: usize, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..warmup {
+        black_box(f());
+    }
+
+    let mut samples: Vec<_> = (0..iterations)
+        .map(|_| {
+            let start_time = Instant::now();
+            let output = f();
+            let elapsed = start_time.elapsed();
+
+            // Don't count the time spent dropping the output
+            black_box(output);
+            elapsed
+        })
+        .collect();
+
+    Stats::from_samples(&mut samples)
+}
+
+/// Times a solution's generator and solver separately, after `warmup` untimed runs.
+pub fn bench(
+    solution: &Solution,
+    input: &str,
+    warmup: usize,
+    iterations: usize,
+) -> Result<Report, SolveError> {
+    // Fail before timing anything if the input or solution is malformed
+    let generated = (solution.generate)(input)?;
+
+    let answer = (solution.solve)(&*generated)?;
+
+    let generator = sample(warmup, iterations, || (solution.generate)(input));
+    let solve = sample(warmup, iterations, || (solution.solve)(&*generated));
+
+    Ok(Report {
+        year: solution.year,
+        day: solution.day,
+        part: solution.part,
+        name: solution.name,
+        iterations,
+        answer,
+        generator,
+        solve,
+    })
+}
+
+#[test]
+fn test_stats() {
+    let ms = Duration::from_millis;
+    let mut samples: Vec<_> = (1..=20).rev().map(ms).collect();
+    let stats = Stats::from_samples(&mut samples);
+
+    assert_eq!(stats.min, ms(1));
+    assert_eq!(stats.median, Duration::from_micros(10_500));
+    assert_eq!(stats.p95, ms(19));
+    assert_eq!(stats.mean, Duration::from_micros(10_500));
+
+    let stats = Stats::from_samples(&mut [ms(3)]);
+
+    assert_eq!((stats.min, stats.median, stats.p95), (ms(3), ms(3), ms(3)));
+}
+
+#[test]
+fn test_report_json() {
+    let ms = Duration::from_millis;
+    let stats = Stats::from_samples(&mut [ms(1), ms(2), ms(3)]);
+    let report = Report {
+        year: 2025,
+        day: 1,
+        part: 2,
+        name: None,
+        iterations: 3,
+        answer: Answer::from((21, 53)),
+        generator: stats,
+        solve: stats,
+    };
+    let json = serde_json::to_value(&report).unwrap();
+
+    assert_eq!(json["impl"], serde_json::Value::Null);
+    assert_eq!(json["answer"], serde_json::json!([21, 53]));
+    assert_eq!(json["solve"]["median_ns"], 2_000_000);
+    assert_eq!(json["generator"]["p95_ns"], 3_000_000);
+}
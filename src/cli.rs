@@ -0,0 +1,141 @@
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+Usage: aoc [COMMAND] [OPTIONS]
+
+Commands:
+    run      Run solutions and print their answers (default)
+    list     List registered solutions
+    check    Compare solutions against the answers in answers/<year>.toml
+    bench    Time the generator and solver of each solution
+
+Options:
+    --year <YEAR>    Select a year, defaults to the latest one (every year for check)
+    --day <DAY>      Select a single day
+    --part <PART>    Select a single part
+    --impl <NAME>    Select an alternate implementation, ie Chars
+    --input <PATH>   Read the input from PATH, requires --day
+    --iterations <N> Number of timed runs for bench, defaults to 10
+    --warmup <N>     Number of untimed runs before timing, defaults to 3
+    --json           Print bench results as JSON
+    -h, --help       Print this message";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    Run,
+    List,
+    Check,
+    Bench,
+    Help,
+}
+
+#[derive(Debug)]
+pub struct Args {
+    pub command: Command,
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub part: Option<u8>,
+    pub implementation: Option<String>,
+    pub input: Option<PathBuf>,
+    pub iterations: usize,
+    pub warmup: usize,
+    pub json: bool,
+}
+
+fn value<T: std::str::FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("{} requires a value", flag))?;
+
+    value
+        .parse()
+        .map_err(|_| format!("invalid value for {}: {}", flag, value))
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.into_iter().peekable();
+        let command = match args.peek().map(String::as_str) {
+            Some("run") => Command::Run,
+            Some("list") => Command::List,
+            Some("check") => Command::Check,
+            Some("bench") => Command::Bench,
+            Some("help") => Command::Help,
+            Some(arg) if !arg.starts_with('-') => return Err(format!("unknown command: {}", arg)),
+            _ => Command::Run,
+        };
+
+        if args.peek().is_some_and(|arg| !arg.starts_with('-')) {
+            args.next();
+        }
+
+        let mut parsed = Args {
+            command,
+            year: None,
+            day: None,
+            part: None,
+            implementation: None,
+            input: None,
+            iterations: 10,
+            warmup: 3,
+            json: false,
+        };
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--year" => parsed.year = Some(value(&arg, args.next())?),
+                "--day" => parsed.day = Some(value(&arg, args.next())?),
+                "--part" => parsed.part = Some(value(&arg, args.next())?),
+                "--impl" => parsed.implementation = Some(value(&arg, args.next())?),
+                "--input" => parsed.input = Some(value(&arg, args.next())?),
+                "--iterations" => parsed.iterations = value(&arg, args.next())?,
+                "--warmup" => parsed.warmup = value(&arg, args.next())?,
+                "--json" => parsed.json = true,
+                "-h" | "--help" => parsed.command = Command::Help,
+                _ => return Err(format!("unexpected argument: {}", arg)),
+            }
+        }
+
+        if parsed.iterations == 0 {
+            return Err("--iterations must be at least 1".into());
+        }
+
+        if parsed.input.is_some() && parsed.day.is_none() {
+            return Err("--input requires --day".into());
+        }
+
+        Ok(parsed)
+    }
+}
+
+#[test]
+fn test_parse_args() {
+    let args = |s: &str| Args::parse(s.split_whitespace().map(String::from));
+
+    let parsed = args("").unwrap();
+
+    assert_eq!(parsed.command, Command::Run);
+    assert_eq!(parsed.year, None);
+
+    let parsed = args("check --year 2022 --day 7 --part 2 --impl Chars").unwrap();
+
+    assert_eq!(parsed.command, Command::Check);
+    assert_eq!(parsed.year, Some(2022));
+    assert_eq!(parsed.day, Some(7));
+    assert_eq!(parsed.part, Some(2));
+    assert_eq!(parsed.implementation.as_deref(), Some("Chars"));
+
+    let parsed = args("--day 3 --input example.txt").unwrap();
+
+    assert_eq!(parsed.command, Command::Run);
+    assert_eq!(parsed.input, Some(PathBuf::from("example.txt")));
+
+    let parsed = args("bench --iterations 50 --json").unwrap();
+
+    assert_eq!(parsed.command, Command::Bench);
+    assert_eq!((parsed.iterations, parsed.warmup, parsed.json), (50, 3, true));
+
+    assert!(args("bench --iterations 0").is_err());
+    assert!(args("--input example.txt").is_err());
+    assert!(args("--day three").is_err());
+    assert!(args("--year").is_err());
+    assert!(args("solve").is_err());
+}
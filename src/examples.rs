@@ -0,0 +1,89 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::answer::Answer;
+use crate::answers::parse_parts;
+use crate::input;
+use crate::registry;
+
+/// A puzzle example stored as `examples/<year>/dayN/<name>.txt`, with its
+/// expected answers in `<name>.toml`:
+///
+/// ```toml
+/// part1 = 95437
+/// part2 = 24933642
+/// ```
+///
+/// Parts without an expected answer are not checked.
+#[derive(Debug)]
+pub struct Example {
+    pub year: u16,
+    pub day: u8,
+    pub name: String,
+    pub input: String,
+    pub expected: BTreeMap<u8, Answer>,
+}
+
+/// Where the examples for a given day are stored: `examples/<year>/dayN/`
+pub fn dir(year: u16, day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("examples")
+        .join(year.to_string())
+        .join(format!("day{}", day))
+}
+
+impl Example {
+    pub fn load(year: u16, day: u8, name: &str) -> Result<Self, String> {
+        let dir = dir(year, day);
+        let input = input::read(&dir.join(format!("{}.txt", name))).map_err(|e| e.to_string())?;
+        let expected_path = dir.join(format!("{}.toml", name));
+        let expected = fs::read_to_string(&expected_path)
+            .map_err(|e| format!("{}: {}", expected_path.display(), e))?;
+        let expected = toml::from_str(&expected)
+            .map_err(|e| e.message().to_owned())
+            .and_then(parse_parts)
+            .map_err(|e| format!("{}: {}", expected_path.display(), e))?;
+
+        Ok(Example {
+            year,
+            day,
+            name: name.to_owned(),
+            input,
+            expected,
+        })
+    }
+}
+
+/// Runs every implementation of each expected part against an example,
+/// panicking on the first mismatch. Used by the generated example tests.
+pub fn check(year: u16, day: u8, name: &str) {
+    let example = Example::load(year, day, name).unwrap_or_else(|e| panic!("{}", e));
+
+    assert!(!example.expected.is_empty(), "{} has no expected answers", name);
+
+    for (&part, expected) in &example.expected {
+        let mut solutions = registry::find(Some(year), Some(day))
+            .filter(|s| s.part == part && !s.baseline)
+            .peekable();
+
+        assert!(solutions.peek().is_some(), "No solution for {} Day {} - Part {}", year, day, part);
+
+        for solution in solutions {
+            let actual = match solution.run(&example.input) {
+                Ok(actual) => actual,
+                Err(e) => panic!("{} {} on example {}: {}", year, solution, name, e),
+            };
+
+            assert!(
+                *expected == actual,
+                "{} {} on example {}\n\texpected: {}\n\tgot: {}",
+                year,
+                solution,
+                name,
+                expected,
+                actual,
+            );
+        }
+    }
+}
@@ -0,0 +1,321 @@
+//! Points and directions on a plane with y going down, as it does in puzzle
+//! maps and `Grid`: `Dir4::Up` is `(0, -1)`.
+
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position on the plane
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+/// The difference between two points
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector<T = i64> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T> Vector<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Vector { x, y }
+    }
+}
+
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add<Vector<T>> for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, rhs: Vector<T>) -> Point<T> {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub<Vector<T>> for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, rhs: Vector<T>) -> Point<T> {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, rhs: Point<T>) -> Vector<T> {
+        Vector::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Copy + Add<Output = T>> AddAssign<Vector<T>> for Point<T> {
+    fn add_assign(&mut self, rhs: Vector<T>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Copy + Sub<Output = T>> SubAssign<Vector<T>> for Point<T> {
+    fn sub_assign(&mut self, rhs: Vector<T>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vector<T> {
+    type Output = Vector<T>;
+
+    fn add(self, rhs: Vector<T>) -> Vector<T> {
+        Vector::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vector<T> {
+    type Output = Vector<T>;
+
+    fn sub(self, rhs: Vector<T>) -> Vector<T> {
+        Vector::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Vector<T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: T) -> Vector<T> {
+        Vector::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vector<T> {
+    type Output = Vector<T>;
+
+    fn neg(self) -> Vector<T> {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+macro_rules! signed {
+    ($($int:ty),*) => {
+        $(
+            impl Point<$int> {
+                pub fn manhattan(self, other: Self) -> $int {
+                    (other - self).manhattan_len()
+                }
+
+                pub fn chebyshev(self, other: Self) -> $int {
+                    (other - self).chebyshev_len()
+                }
+
+                /// Moves one step (diagonals included) towards `target`.
+                pub fn step_towards(self, target: Self) -> Self {
+                    self + (target - self).signum()
+                }
+            }
+
+            impl Vector<$int> {
+                pub fn manhattan_len(self) -> $int {
+                    self.x.abs() + self.y.abs()
+                }
+
+                pub fn chebyshev_len(self) -> $int {
+                    self.x.abs().max(self.y.abs())
+                }
+
+                /// The vector with each component reduced to -1, 0 or 1.
+                pub fn signum(self) -> Self {
+                    Vector::new(self.x.signum(), self.y.signum())
+                }
+
+                /// Rotates a quarter turn clockwise, as seen on screen.
+                pub fn rotate_cw(self) -> Self {
+                    Vector::new(-self.y, self.x)
+                }
+
+                /// Rotates a quarter turn counterclockwise, as seen on screen.
+                pub fn rotate_ccw(self) -> Self {
+                    Vector::new(self.y, -self.x)
+                }
+            }
+        )*
+    };
+}
+
+signed!(i32, i64, isize);
+
+/// Converts a `Grid` position.
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point::new(x as i64, y as i64)
+    }
+}
+
+/// The 4 orthogonal directions
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    /// All directions, in reading order of the squares they lead to
+    pub const READING_ORDER: [Dir4; 4] = [Dir4::Up, Dir4::Left, Dir4::Right, Dir4::Down];
+
+    /// Parses `U`/`D`/`L`/`R`, `N`/`S`/`W`/`E` or `^`/`v`/`<`/`>`.
+    pub fn from_char(ch: char) -> Option<Self> {
+        match ch {
+            'U' | 'N' | '^' => Some(Dir4::Up),
+            'D' | 'S' | 'v' => Some(Dir4::Down),
+            'L' | 'W' | '<' => Some(Dir4::Left),
+            'R' | 'E' | '>' => Some(Dir4::Right),
+            _ => None,
+        }
+    }
+
+    pub fn delta(self) -> Vector {
+        match self {
+            Dir4::Up => Vector::new(0, -1),
+            Dir4::Right => Vector::new(1, 0),
+            Dir4::Down => Vector::new(0, 1),
+            Dir4::Left => Vector::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Dir4::Up => Dir4::Right,
+            Dir4::Right => Dir4::Down,
+            Dir4::Down => Dir4::Left,
+            Dir4::Left => Dir4::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn_right().turn_right().turn_right()
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+}
+
+/// The 8 directions, diagonals included, clockwise from up
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    /// Parses the orthogonal directions, like `Dir4::from_char`.
+    pub fn from_char(ch: char) -> Option<Self> {
+        Dir4::from_char(ch).map(Dir8::from)
+    }
+
+    /// The direction a vector points in, if it's along one of the 8 directions.
+    pub fn from_delta(delta: Vector) -> Option<Self> {
+        let unit = delta.signum();
+
+        if unit * delta.chebyshev_len() != delta || unit == Vector::default() {
+            return None;
+        }
+
+        Dir8::ALL.into_iter().find(|dir| dir.delta() == unit)
+    }
+
+    pub fn delta(self) -> Vector {
+        match self {
+            Dir8::Up => Vector::new(0, -1),
+            Dir8::UpRight => Vector::new(1, -1),
+            Dir8::Right => Vector::new(1, 0),
+            Dir8::DownRight => Vector::new(1, 1),
+            Dir8::Down => Vector::new(0, 1),
+            Dir8::DownLeft => Vector::new(-1, 1),
+            Dir8::Left => Vector::new(-1, 0),
+            Dir8::UpLeft => Vector::new(-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        match dir {
+            Dir4::Up => Dir8::Up,
+            Dir4::Right => Dir8::Right,
+            Dir4::Down => Dir8::Down,
+            Dir4::Left => Dir8::Left,
+        }
+    }
+}
+
+#[test]
+fn test_point_arithmetic() {
+    let a: Point = Point::new(1, 2);
+    let b = Point::new(4, -2);
+
+    assert_eq!(b - a, Vector::new(3, -4));
+    assert_eq!(a + Vector::new(3, -4), b);
+    assert_eq!(a.manhattan(b), 7);
+    assert_eq!(a.chebyshev(b), 4);
+    assert_eq!(a.step_towards(b), Point::new(2, 1));
+    assert_eq!(a.step_towards(a), a);
+    assert_eq!(Point::from((3, 5)), Point::new(3, 5));
+    assert_eq!(Vector::new(3, -4) * 2, Vector::new(6, -8));
+    assert_eq!(-Vector::new(3, -4), Vector::new(-3, 4));
+}
+
+#[test]
+fn test_rotation() {
+    let up = Dir4::Up.delta();
+
+    assert_eq!(up.rotate_cw(), Dir4::Right.delta());
+    assert_eq!(up.rotate_ccw(), Dir4::Left.delta());
+    assert_eq!(up.rotate_cw().rotate_cw(), Dir4::Down.delta());
+    assert_eq!(Dir4::Left.turn_right(), Dir4::Up);
+    assert_eq!(Dir4::Left.turn_left(), Dir4::Down);
+    assert_eq!(Dir4::Right.opposite(), Dir4::Left);
+}
+
+#[test]
+fn test_dirs() {
+    assert_eq!(Dir4::from_char('U'), Some(Dir4::Up));
+    assert_eq!(Dir4::from_char('>'), Some(Dir4::Right));
+    assert_eq!(Dir4::from_char('x'), None);
+    assert_eq!(Dir8::from_char('L'), Some(Dir8::Left));
+    assert_eq!(Dir8::from_delta(Vector::new(3, -3)), Some(Dir8::UpRight));
+    assert_eq!(Dir8::from_delta(Vector::new(0, 2)), Some(Dir8::Down));
+    assert_eq!(Dir8::from_delta(Vector::new(1, 2)), None);
+    assert_eq!(Dir8::from_delta(Vector::new(0, 0)), None);
+
+    for dir in Dir8::ALL {
+        assert_eq!(Dir8::from_delta(dir.delta()), Some(dir));
+    }
+}
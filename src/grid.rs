@@ -0,0 +1,287 @@
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+use crate::parse::{ParseError, Source};
+
+/// A position in a grid, as (x, y) with y going down
+pub type Pos = (usize, usize);
+
+/// Offsets of the 4 orthogonal neighbours, in reading order
+const NEIGHBOURS4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of all 8 neighbours, in reading order
+const NEIGHBOURS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid stored row by row, addressable by (x, y) or by flat
+/// index (`y * width + x`).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Wraps cells stored row by row.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "Grid cells don't match its dimensions");
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut f)
+            .collect();
+
+        Grid::new(width, height, cells)
+    }
+
+    /// Parses a map of one char per cell. Fails on chars `f` doesn't map, and
+    /// on rows that aren't as wide as the first.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let source = Source::new(input);
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        let mut cells = Vec::with_capacity(input.len());
+        let mut height = 0;
+
+        for line in input.lines() {
+            let row_start = cells.len();
+
+            for (i, ch) in line.char_indices() {
+                cells.push(f(ch).ok_or_else(|| source.unexpected(line, i))?);
+            }
+
+            if cells.len() - row_start != width {
+                return Err(source.error(
+                    line,
+                    format!("expected {} columns, found {}", width, cells.len() - row_start),
+                ));
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid::new(width, height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn in_bounds(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// The flat index of a position
+    pub fn index_of(&self, (x, y): Pos) -> usize {
+        y * self.width + x
+    }
+
+    /// The position of a flat index
+    pub fn pos_of(&self, index: usize) -> Pos {
+        (index % self.width, index / self.width)
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.in_bounds(pos).then(|| &self.cells[self.index_of(pos)])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if !self.in_bounds(pos) {
+            return None;
+        }
+
+        let index = self.index_of(pos);
+
+        Some(&mut self.cells[index])
+    }
+
+    /// All cells in reading order
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// All positions in reading order
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// All cells along with their position, in reading order
+    pub fn enumerate(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on a chunk size of 0
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {} out of bounds", x);
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    fn offset(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let pos = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+
+        self.in_bounds(pos).then_some(pos)
+    }
+
+    /// The up to 4 orthogonal neighbours of a position, in reading order.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS4.into_iter().filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    /// The up to 8 neighbours of a position, diagonals included, in reading order.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS8.into_iter().filter_map(move |delta| self.offset(pos, delta))
+    }
+
+    /// The positions from `pos` in steps of `delta`, up to the edge of the
+    /// grid. Doesn't include `pos` itself.
+    pub fn ray(&self, pos: Pos, delta: (isize, isize)) -> impl Iterator<Item = Pos> + '_ {
+        let mut pos = Some(pos);
+
+        std::iter::from_fn(move || {
+            pos = self.offset(pos?, delta);
+            pos
+        })
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        assert!(self.in_bounds(pos), "{:?} out of bounds", pos);
+
+        &self.cells[self.index_of(pos)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(self.in_bounds(pos), "{:?} out of bounds", pos);
+
+        let index = self.index_of(pos);
+
+        &mut self.cells[index]
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.cells[index]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.cells[index]
+    }
+}
+
+/// Draws each cell in turn, with every row ending in a newline.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_grid_parse() {
+    let grid = Grid::parse("#.#\n..#", |ch| match ch {
+        '#' => Some(1),
+        '.' => Some(0),
+        _ => None,
+    })
+    .unwrap();
+
+    assert_eq!((grid.width(), grid.height(), grid.len()), (3, 2, 6));
+    assert_eq!(grid[(2, 1)], 1);
+    assert_eq!(grid[4], 0);
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid.pos_of(5), (2, 1));
+    assert_eq!(grid.index_of((1, 1)), 4);
+    assert_eq!(grid.row(1), &[0, 0, 1]);
+    assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [1, 1]);
+    assert_eq!(grid.to_string(), "101\n001\n");
+
+    let err = Grid::parse("#.#\n.x#", |ch| (ch != 'x').then_some(ch)).unwrap_err();
+
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "x"));
+
+    let err = Grid::parse("#.#\n.#", Some).unwrap_err();
+
+    assert_eq!(err.message, "expected 3 columns, found 2");
+}
+
+#[test]
+fn test_grid_neighbours() {
+    let grid = Grid::from_fn(3, 3, |(x, y)| x + y * 3);
+
+    assert_eq!(grid.neighbours4((0, 0)).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+    assert_eq!(
+        grid.neighbours4((1, 1)).collect::<Vec<_>>(),
+        [(1, 0), (0, 1), (2, 1), (1, 2)]
+    );
+    assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    assert_eq!(
+        grid.neighbours8((2, 2)).collect::<Vec<_>>(),
+        [(1, 1), (2, 1), (1, 2)]
+    );
+    assert_eq!(grid.ray((0, 2), (1, -1)).collect::<Vec<_>>(), [(1, 1), (2, 0)]);
+    assert_eq!(grid.ray((0, 1), (-1, 0)).count(), 0);
+}
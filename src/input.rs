@@ -0,0 +1,84 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum InputError {
+    /// No input file was found where one was expected
+    Missing { path: PathBuf },
+    /// The input file exists but could not be read
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            InputError::Missing { path } => write!(f, "no input found at {}", path.display()),
+            InputError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Missing { .. } => None,
+            InputError::Io { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Where the puzzle input for a given day is stored: `input/<year>/dayN.txt`
+pub fn path(year: u16, day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("input")
+        .join(year.to_string())
+        .join(format!("day{}.txt", day))
+}
+
+/// Converts CRLF line endings to LF and strips any trailing newlines, so
+/// solutions can split on `'\n'` without handling an empty last line.
+pub fn normalize(raw: &str) -> String {
+    raw.replace("\r\n", "\n").trim_end_matches('\n').to_owned()
+}
+
+/// Reads and normalizes an input file from an arbitrary path.
+pub fn read(path: &Path) -> Result<String, InputError> {
+    match fs::read_to_string(path) {
+        Ok(raw) => Ok(normalize(&raw)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(InputError::Missing {
+            path: path.to_owned(),
+        }),
+        Err(source) => Err(InputError::Io {
+            path: path.to_owned(),
+            source,
+        }),
+    }
+}
+
+/// Reads and normalizes the stored puzzle input for a given day.
+pub fn load(year: u16, day: u8) -> Result<String, InputError> {
+    read(&path(year, day))
+}
+
+#[test]
+fn test_normalize() {
+    assert_eq!(normalize("noop\r\naddx 3\r\n"), "noop\naddx 3");
+    assert_eq!(normalize("1\n2\n\n"), "1\n2");
+    assert_eq!(normalize("1\n\n2"), "1\n\n2");
+}
+
+#[test]
+fn test_load() {
+    let input = load(2022, 10).unwrap();
+
+    assert!(input.starts_with("noop"));
+    assert!(!input.ends_with('\n'));
+
+    match load(2022, 25) {
+        Err(InputError::Missing { path }) => assert!(path.ends_with("input/2022/day25.txt")),
+        other => panic!("expected a missing input, got {:?}", other),
+    }
+}
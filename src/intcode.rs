@@ -0,0 +1,407 @@
+//! The Intcode computer from 2019, shared by every day that runs a program on
+//! it. A `Vm` runs until it needs input, has output ready or halts, and can be
+//! resumed from there, so several can be chained together.
+
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::parse::{ParseError, Source};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    /// The parameter is an address
+    Position,
+    /// The parameter is the value itself
+    Immediate,
+    /// The parameter is an address relative to the relative base
+    Relative,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Mul,
+    Input,
+    Output,
+    JumpIfTrue,
+    JumpIfFalse,
+    LessThan,
+    Equals,
+    AdjustBase,
+    Halt,
+}
+
+impl Op {
+    fn from_code(code: i64) -> Option<Self> {
+        Some(match code {
+            1 => Op::Add,
+            2 => Op::Mul,
+            3 => Op::Input,
+            4 => Op::Output,
+            5 => Op::JumpIfTrue,
+            6 => Op::JumpIfFalse,
+            7 => Op::LessThan,
+            8 => Op::Equals,
+            9 => Op::AdjustBase,
+            99 => Op::Halt,
+            _ => return None,
+        })
+    }
+
+    /// The number of parameters after the opcode
+    pub fn arity(self) -> usize {
+        match self {
+            Op::Add | Op::Mul | Op::LessThan | Op::Equals => 3,
+            Op::JumpIfTrue | Op::JumpIfFalse => 2,
+            Op::Input | Op::Output | Op::AdjustBase => 1,
+            Op::Halt => 0,
+        }
+    }
+
+    pub fn mnemonic(self) -> &'static str {
+        match self {
+            Op::Add => "add",
+            Op::Mul => "mul",
+            Op::Input => "in",
+            Op::Output => "out",
+            Op::JumpIfTrue => "jnz",
+            Op::JumpIfFalse => "jz",
+            Op::LessThan => "lt",
+            Op::Equals => "eq",
+            Op::AdjustBase => "arb",
+            Op::Halt => "hlt",
+        }
+    }
+}
+
+/// An opcode with the modes of its parameters
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    pub op: Op,
+    pub modes: [Mode; 3],
+}
+
+impl Instruction {
+    /// Splits a value into its opcode, the last two digits, and the mode
+    /// digits above them.
+    pub fn decode(value: i64) -> Option<Self> {
+        if value < 0 {
+            return None;
+        }
+
+        let op = Op::from_code(value % 100)?;
+        let mut modes = [Mode::Position; 3];
+        let mut digits = value / 100;
+
+        for mode in &mut modes {
+            *mode = match digits % 10 {
+                0 => Mode::Position,
+                1 => Mode::Immediate,
+                2 => Mode::Relative,
+                _ => return None,
+            };
+            digits /= 10;
+        }
+
+        (digits == 0).then_some(Instruction { op, modes })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IntcodeError {
+    /// A value that isn't a known opcode, or has a bad mode digit
+    BadInstruction { addr: usize, value: i64 },
+    /// A parameter that's written to is in immediate mode
+    ImmediateWrite { addr: usize },
+    NegativeAddress { addr: usize, value: i64 },
+}
+
+impl Display for IntcodeError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            IntcodeError::BadInstruction { addr, value } => {
+                write!(f, "bad instruction {} at address {}", value, addr)
+            }
+            IntcodeError::ImmediateWrite { addr } => {
+                write!(f, "the instruction at address {} writes to an immediate", addr)
+            }
+            IntcodeError::NegativeAddress { addr, value } => {
+                write!(f, "the instruction at address {} uses address {}", addr, value)
+            }
+        }
+    }
+}
+
+impl Error for IntcodeError {}
+
+/// Why the VM stopped
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    /// Ready for the next instruction
+    Running,
+    /// Stopped at an input instruction with nothing queued
+    NeedsInput,
+    Output(i64),
+    Halted,
+}
+
+#[derive(Clone, Debug)]
+pub struct Vm {
+    /// Grows as addresses past the end are written to. Reading past the end
+    /// gives 0.
+    memory: Vec<i64>,
+    ip: usize,
+    relative_base: i64,
+    input: VecDeque<i64>,
+}
+
+impl Vm {
+    pub fn new(program: Vec<i64>) -> Self {
+        Vm {
+            memory: program,
+            ip: 0,
+            relative_base: 0,
+            input: VecDeque::new(),
+        }
+    }
+
+    /// Parses a comma separated program.
+    pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        let source = Source::new(input);
+
+        input
+            .trim()
+            .split(',')
+            .map(|value| source.parse(value.trim()))
+            .collect()
+    }
+
+    pub fn memory(&self) -> &[i64] {
+        &self.memory
+    }
+
+    pub fn read(&self, addr: usize) -> i64 {
+        self.memory.get(addr).copied().unwrap_or(0)
+    }
+
+    pub fn write(&mut self, addr: usize, value: i64) {
+        if addr >= self.memory.len() {
+            self.memory.resize(addr + 1, 0);
+        }
+
+        self.memory[addr] = value;
+    }
+
+    /// Queues a value for input instructions to read.
+    pub fn push_input(&mut self, value: i64) {
+        self.input.push_back(value);
+    }
+
+    /// The address parameter `index` of the current instruction points at.
+    fn param_addr(&self, instr: Instruction, index: usize) -> Result<usize, IntcodeError> {
+        let param = self.ip + 1 + index;
+        let addr = match instr.modes[index] {
+            Mode::Position => self.read(param),
+            Mode::Relative => self.relative_base + self.read(param),
+            Mode::Immediate => return Err(IntcodeError::ImmediateWrite { addr: self.ip }),
+        };
+
+        usize::try_from(addr).map_err(|_| IntcodeError::NegativeAddress {
+            addr: self.ip,
+            value: addr,
+        })
+    }
+
+    fn param(&self, instr: Instruction, index: usize) -> Result<i64, IntcodeError> {
+        match instr.modes[index] {
+            Mode::Immediate => Ok(self.read(self.ip + 1 + index)),
+            _ => Ok(self.read(self.param_addr(instr, index)?)),
+        }
+    }
+
+    /// Executes a single instruction. Input and halt instructions that can't
+    /// go ahead leave the VM where it is, so stepping again retries them.
+    pub fn step(&mut self) -> Result<Status, IntcodeError> {
+        let value = self.read(self.ip);
+        let instr = Instruction::decode(value).ok_or(IntcodeError::BadInstruction {
+            addr: self.ip,
+            value,
+        })?;
+        let mut next = self.ip + 1 + instr.op.arity();
+        let mut status = Status::Running;
+
+        match instr.op {
+            Op::Add | Op::Mul | Op::LessThan | Op::Equals => {
+                let (lhs, rhs) = (self.param(instr, 0)?, self.param(instr, 1)?);
+                let result = match instr.op {
+                    Op::Add => lhs + rhs,
+                    Op::Mul => lhs * rhs,
+                    Op::LessThan => (lhs < rhs) as i64,
+                    _ => (lhs == rhs) as i64,
+                };
+
+                self.write(self.param_addr(instr, 2)?, result);
+            }
+            Op::Input => {
+                let addr = self.param_addr(instr, 0)?;
+                let Some(value) = self.input.pop_front() else {
+                    return Ok(Status::NeedsInput);
+                };
+
+                self.write(addr, value);
+            }
+            Op::Output => status = Status::Output(self.param(instr, 0)?),
+            Op::JumpIfTrue | Op::JumpIfFalse => {
+                let jump = (self.param(instr, 0)? != 0) == (instr.op == Op::JumpIfTrue);
+
+                if jump {
+                    let target = self.param(instr, 1)?;
+
+                    next = usize::try_from(target).map_err(|_| IntcodeError::NegativeAddress {
+                        addr: self.ip,
+                        value: target,
+                    })?;
+                }
+            }
+            Op::AdjustBase => self.relative_base += self.param(instr, 0)?,
+            Op::Halt => return Ok(Status::Halted),
+        }
+
+        self.ip = next;
+
+        Ok(status)
+    }
+
+    /// Steps until the VM needs input, has output ready or halts.
+    pub fn resume(&mut self) -> Result<Status, IntcodeError> {
+        loop {
+            match self.step()? {
+                Status::Running => {}
+                status => return Ok(status),
+            }
+        }
+    }
+
+    /// Feeds in `inputs` and runs until halting, collecting the outputs. Runs
+    /// out of input are treated as halting.
+    pub fn run(&mut self, inputs: &[i64]) -> Result<Vec<i64>, IntcodeError> {
+        let mut outputs = Vec::new();
+
+        self.input.extend(inputs);
+
+        loop {
+            match self.resume()? {
+                Status::Output(value) => outputs.push(value),
+                _ => return Ok(outputs),
+            }
+        }
+    }
+}
+
+fn operand(mode: Mode, value: i64) -> String {
+    match mode {
+        Mode::Position => format!("[{}]", value),
+        Mode::Immediate => value.to_string(),
+        Mode::Relative if value < 0 => format!("[rb-{}]", -value),
+        Mode::Relative => format!("[rb+{}]", value),
+    }
+}
+
+/// Lists a program one instruction per line, ie `   4: mul [5], 3, [rb+2]`.
+/// Values that don't decode, like data after the code, are shown as `data`.
+pub fn disassemble(program: &[i64]) -> String {
+    let mut listing = String::new();
+    let mut addr = 0;
+
+    while addr < program.len() {
+        let value = program[addr];
+        let line = match Instruction::decode(value) {
+            Some(instr) if addr + instr.op.arity() < program.len() => {
+                let operands: Vec<String> = (0..instr.op.arity())
+                    .map(|i| operand(instr.modes[i], program[addr + 1 + i]))
+                    .collect();
+                let line = format!("{:>4}: {} {}", addr, instr.op.mnemonic(), operands.join(", "));
+
+                addr += 1 + instr.op.arity();
+                line
+            }
+            _ => {
+                addr += 1;
+                format!("{:>4}: data {}", addr - 1, value)
+            }
+        };
+
+        listing.push_str(line.trim_end());
+        listing.push('\n');
+    }
+
+    listing
+}
+
+#[test]
+fn test_intcode_day2() {
+    let mut vm = Vm::new(Vm::parse("1,9,10,3,2,3,11,0,99,30,40,50\n").unwrap());
+
+    assert_eq!(vm.resume(), Ok(Status::Halted));
+    assert_eq!(vm.memory(), [3500, 9, 10, 70, 2, 3, 11, 0, 99, 30, 40, 50]);
+    assert!(Vm::parse("1,2,x").is_err());
+}
+
+#[test]
+fn test_intcode_io() {
+    // Outputs 999, 1000 or 1001 for input below, equal to or above 8
+    let program = Vm::parse(
+        "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99",
+    )
+    .unwrap();
+
+    for (input, output) in [(7, 999), (8, 1000), (9, 1001)] {
+        assert_eq!(Vm::new(program.clone()).run(&[input]), Ok(vec![output]));
+    }
+
+    let mut vm = Vm::new(program);
+
+    assert_eq!(vm.resume(), Ok(Status::NeedsInput));
+    assert_eq!(vm.resume(), Ok(Status::NeedsInput));
+
+    vm.push_input(8);
+
+    assert_eq!(vm.resume(), Ok(Status::Output(1000)));
+    assert_eq!(vm.resume(), Ok(Status::Halted));
+    assert_eq!(vm.resume(), Ok(Status::Halted));
+}
+
+#[test]
+fn test_intcode_relative() {
+    // A quine, which needs relative mode and memory past the program
+    let quine = vec![109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99];
+
+    assert_eq!(Vm::new(quine.clone()).run(&[]), Ok(quine));
+    assert_eq!(Vm::new(vec![104, 1125899906842624, 99]).run(&[]), Ok(vec![1125899906842624]));
+    assert_eq!(
+        Vm::new(vec![1102, 34915192, 34915192, 7, 4, 7, 99, 0]).run(&[]),
+        Ok(vec![1219070632396864])
+    );
+}
+
+#[test]
+fn test_intcode_errors() {
+    assert_eq!(
+        Vm::new(vec![1, 0, 0, 0, 42]).resume(),
+        Err(IntcodeError::BadInstruction { addr: 4, value: 42 })
+    );
+    assert_eq!(Vm::new(vec![11101, 1, 1, 0]).resume(), Err(IntcodeError::ImmediateWrite { addr: 0 }));
+    assert_eq!(
+        Vm::new(vec![4, -1]).resume(),
+        Err(IntcodeError::NegativeAddress { addr: 0, value: -1 })
+    );
+}
+
+#[test]
+fn test_disassemble() {
+    assert_eq!(
+        disassemble(&[1002, 4, 3, 4, 33, 109, -3, 204, 2, 99, 7]),
+        "   0: mul [4], 3, [4]\n   4: data 33\n   5: arb -3\n   7: out [rb+2]\n   9: hlt\n  10: data 7\n"
+    );
+}
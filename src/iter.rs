@@ -0,0 +1,291 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+use std::iter::Fuse;
+
+/// Extra iterator adaptors and consumers the solutions keep needing
+pub trait IterExt: Iterator + Sized {
+    /// Like `take_while`, but also yields the first item that fails the
+    /// predicate, ie counting the trees up to and including the one that
+    /// blocks the view.
+    fn take_while_inclusive<F>(self, predicate: F) -> TakeWhileInclusive<Self, F>
+    where
+        F: FnMut(&Self::Item) -> bool,
+    {
+        TakeWhileInclusive {
+            iter: self,
+            predicate,
+            done: false,
+        }
+    }
+
+    /// The smallest and largest items in one pass. Ties go to the first
+    /// smallest and the last largest, like `min` and `max`.
+    fn min_max(mut self) -> Option<(Self::Item, Self::Item)>
+    where
+        Self::Item: Ord + Clone,
+    {
+        let first = self.next()?;
+
+        Some(self.fold((first.clone(), first), |(min, max), item| {
+            if item < min {
+                (item, max)
+            } else if item >= max {
+                (min, item)
+            } else {
+                (min, max)
+            }
+        }))
+    }
+
+    /// How many times each item appears
+    fn counts(self) -> HashMap<Self::Item, usize>
+    where
+        Self::Item: Eq + Hash,
+    {
+        let mut counts = HashMap::new();
+
+        for item in self {
+            *counts.entry(item).or_insert(0) += 1;
+        }
+
+        counts
+    }
+
+    /// Every run of `N` consecutive items, like `slice::windows` but for
+    /// iterators that don't have a slice behind them.
+    ///
+    /// # Panics
+    ///
+    /// If `N` is 0.
+    fn windows<const N: usize>(self) -> Windows<Self, N>
+    where
+        Self::Item: Clone,
+    {
+        assert!(N > 0, "windows must hold at least one item");
+
+        Windows {
+            iter: self.fuse(),
+            window: VecDeque::with_capacity(N),
+        }
+    }
+
+    /// Looks for the first item that's the same as an earlier one, ie the
+    /// states of a simulation settling into a loop.
+    fn find_cycle(self) -> Option<Cycle<Self::Item>>
+    where
+        Self::Item: Clone + Eq + Hash,
+    {
+        let mut seen = HashMap::new();
+        let mut items = Vec::new();
+
+        for (index, item) in self.enumerate() {
+            if let Some(&start) = seen.get(&item) {
+                return Some(Cycle {
+                    start,
+                    period: index - start,
+                    items,
+                });
+            }
+
+            seen.insert(item.clone(), index);
+            items.push(item);
+        }
+
+        None
+    }
+
+    /// The index of the largest item by `compare`. Ties go to the first.
+    fn argmax_by<F>(self, mut compare: F) -> Option<usize>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        self.enumerate()
+            .reduce(|best, item| {
+                if compare(&item.1, &best.1) == Ordering::Greater {
+                    item
+                } else {
+                    best
+                }
+            })
+            .map(|(index, _)| index)
+    }
+}
+
+impl<I: Iterator> IterExt for I {}
+
+/// Made by `IterExt::take_while_inclusive`
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct TakeWhileInclusive<I, F> {
+    iter: I,
+    predicate: F,
+    done: bool,
+}
+
+impl<I, F> Iterator for TakeWhileInclusive<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let item = self.iter.next();
+
+        match &item {
+            Some(item) if (self.predicate)(item) => (),
+            _ => self.done = true,
+        }
+
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            // At least one item if there's any left, as even a failing one is yielded
+            let (lower, upper) = self.iter.size_hint();
+
+            (lower.min(1), upper)
+        }
+    }
+}
+
+/// Made by `IterExt::windows`
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct Windows<I: Iterator, const N: usize> {
+    iter: Fuse<I>,
+    /// The last window yielded, or the items so far before the first one
+    window: VecDeque<I::Item>,
+}
+
+impl<I, const N: usize> Iterator for Windows<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.window.len() == N {
+            self.window.pop_front();
+        }
+
+        while self.window.len() < N {
+            self.window.push_back(self.iter.next()?);
+        }
+
+        Some(std::array::from_fn(|i| self.window[i].clone()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Each item still to come finishes one window, apart from the ones
+        // needed to fill the first
+        let missing = (N - self.window.len()).saturating_sub(1);
+        let (lower, upper) = self.iter.size_hint();
+
+        (
+            lower.saturating_sub(missing),
+            upper.map(|upper| upper.saturating_sub(missing)),
+        )
+    }
+}
+
+/// The repeating part of a sequence, found by `IterExt::find_cycle`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle<T> {
+    /// The index of the first item that repeats
+    pub start: usize,
+    /// How many items until it comes round again
+    pub period: usize,
+    /// Every item before the first repeat
+    pub items: Vec<T>,
+}
+
+impl<T> Cycle<T> {
+    /// The item at any index of the sequence, extrapolated from the cycle.
+    pub fn get(&self, index: usize) -> &T {
+        if index < self.start {
+            &self.items[index]
+        } else {
+            &self.items[self.start + (index - self.start) % self.period]
+        }
+    }
+}
+
+#[test]
+fn test_take_while_inclusive() {
+    let mut iter = [1, 2, 5, 3, 6].into_iter().take_while_inclusive(|&n| n < 5);
+
+    assert_eq!(iter.size_hint(), (1, Some(5)));
+    assert_eq!(iter.by_ref().collect::<Vec<_>>(), [1, 2, 5]);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+
+    let mut iter = [1, 2].into_iter().take_while_inclusive(|&n| n < 5);
+
+    assert_eq!(iter.by_ref().collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(std::iter::empty::<u8>().take_while_inclusive(|_| true).size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn test_min_max() {
+    assert_eq!([3, 1, 4, 1, 5, 9, 2].into_iter().min_max(), Some((1, 9)));
+    assert_eq!([7].into_iter().min_max(), Some((7, 7)));
+    assert_eq!(std::iter::empty::<u8>().min_max(), None);
+}
+
+#[test]
+fn test_counts() {
+    let counts = "abracadabra".chars().counts();
+
+    assert_eq!(counts[&'a'], 5);
+    assert_eq!(counts[&'b'], 2);
+    assert_eq!(counts.get(&'z'), None);
+    assert_eq!(counts.values().sum::<usize>(), 11);
+}
+
+#[test]
+fn test_windows() {
+    let mut windows = (1..=5).windows::<3>();
+
+    assert_eq!(windows.size_hint(), (3, Some(3)));
+    assert_eq!(windows.next(), Some([1, 2, 3]));
+    assert_eq!(windows.size_hint(), (2, Some(2)));
+    assert_eq!(windows.collect::<Vec<_>>(), [[2, 3, 4], [3, 4, 5]]);
+
+    let mut windows = (1..=2).windows::<3>();
+
+    assert_eq!(windows.size_hint(), (0, Some(0)));
+    assert_eq!(windows.next(), None);
+    assert_eq!(windows.next(), None);
+    assert_eq!((1..=3).windows::<1>().count(), 3);
+    assert_eq!((1..).windows::<2>().size_hint(), (usize::MAX - 1, None));
+}
+
+#[test]
+fn test_find_cycle() {
+    // 0, 1, 2, 3, 4, 2, 3, 4, ...
+    let cycle = std::iter::successors(Some(0), |&n| Some(if n == 4 { 2 } else { n + 1 }))
+        .find_cycle()
+        .unwrap();
+
+    assert_eq!((cycle.start, cycle.period), (2, 3));
+    assert_eq!(cycle.items, [0, 1, 2, 3, 4]);
+    assert_eq!(*cycle.get(1), 1);
+    assert_eq!(*cycle.get(5), 2);
+    assert_eq!(*cycle.get(1_000_000_000), 4);
+    assert_eq!((0..10).find_cycle(), None);
+}
+
+#[test]
+fn test_argmax_by() {
+    assert_eq!([3, 9, 2, 9].into_iter().argmax_by(|a, b| a.cmp(b)), Some(1));
+    assert_eq!([3, 9, 2, 9].into_iter().argmax_by(|a, b| b.cmp(a)), Some(2));
+    assert_eq!(std::iter::empty::<u8>().argmax_by(|a, b| a.cmp(b)), None);
+}
@@ -1,16 +1,24 @@
-use aoc_runner_derive::aoc_lib;
-
-pub mod day1;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-// pub mod day7;
-// pub mod day8;
-// pub mod day9;
-// pub mod day10;
-// pub mod day11;
-// pub mod day12;
-
-aoc_lib! { year = 2025 }
+pub mod answer;
+pub mod answers;
+pub mod automaton;
+pub mod bench;
+pub mod examples;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod intcode;
+pub mod iter;
+pub mod ocr;
+pub mod parse;
+pub mod prefix_sum;
+pub mod range_set;
+pub mod registry;
+pub mod search;
+#[cfg(feature = "y2018")]
+pub mod y2018;
+#[cfg(feature = "y2019")]
+pub mod y2019;
+#[cfg(feature = "y2022")]
+pub mod y2022;
+#[cfg(feature = "y2025")]
+pub mod y2025;
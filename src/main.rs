@@ -1,3 +1,197 @@
-use aoc_runner_derive::aoc_main;
+mod cli;
 
-aoc_main! { lib = aoc }
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::env;
+use std::panic::{self, AssertUnwindSafe};
+use std::process::ExitCode;
+use std::time::Instant;
+
+use aoc::answer::Answer;
+use aoc::answers::{Answers, Verdict};
+use aoc::bench;
+use aoc::input::{self, InputError};
+use aoc::registry::{self, Solution};
+use cli::{Args, Command, USAGE};
+
+fn read_input(args: &Args, solution: &Solution) -> Result<String, InputError> {
+    match &args.input {
+        Some(path) => input::read(path),
+        None => input::load(solution.year, solution.day),
+    }
+}
+
+/// Formats an answer to follow a label, moving multi-line ones onto their own lines.
+fn show(answer: &Answer) -> String {
+    if answer.is_multiline() {
+        format!("\n{}", answer)
+    } else {
+        answer.to_string()
+    }
+}
+
+fn run(args: &Args, solutions: &[&Solution]) -> ExitCode {
+    for solution in solutions {
+        let input = match read_input(args, solution) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{}: {}", solution, e);
+                return ExitCode::FAILURE;
+            }
+        };
+        let start_time = Instant::now();
+
+        match solution.run(&input) {
+            Ok(result) => println!("{}: {}\n\ttime: {:?}\n", solution, show(&result), start_time.elapsed()),
+            Err(e) => {
+                eprintln!("{}: {}", solution, e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn check(args: &Args, solutions: &[&Solution]) -> ExitCode {
+    let mut answers = BTreeMap::new();
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    for solution in solutions {
+        let answers = match answers.entry(solution.year) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => match Answers::load(solution.year) {
+                Ok(answers) => entry.insert(answers),
+                Err(e) => {
+                    eprintln!("error: {}", e);
+                    return ExitCode::FAILURE;
+                }
+            },
+        };
+        let outcome = read_input(args, solution)
+            .map_err(|e| e.to_string())
+            .and_then(|input| {
+                panic::catch_unwind(AssertUnwindSafe(|| solution.run(&input)))
+                    .map_err(|_| "panicked".to_owned())?
+                    .map_err(|e| e.to_string())
+            });
+        let actual = match outcome {
+            Ok(actual) => actual,
+            Err(e) => {
+                failed += 1;
+                println!("{} {}: FAIL ({})", solution.year, solution, e);
+                continue;
+            }
+        };
+
+        match answers.check(solution.day, solution.part, &actual) {
+            Verdict::Pass => {
+                passed += 1;
+                println!("{} {}: pass", solution.year, solution);
+            }
+            Verdict::Fail { expected } => {
+                failed += 1;
+                println!(
+                    "{} {}: FAIL\n\texpected: {}\n\tgot: {}",
+                    solution.year,
+                    solution,
+                    show(&expected),
+                    show(&actual)
+                );
+            }
+            Verdict::Missing => {
+                missing += 1;
+                println!("{} {}: missing ({})", solution.year, solution, show(&actual));
+            }
+        }
+    }
+
+    println!("\n{} passed, {} failed, {} missing", passed, failed, missing);
+
+    if failed == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn bench(args: &Args, solutions: &[&Solution]) -> ExitCode {
+    let mut reports = Vec::with_capacity(solutions.len());
+
+    for solution in solutions {
+        let input = match read_input(args, solution) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{}: {}", solution, e);
+                return ExitCode::FAILURE;
+            }
+        };
+        let report = match bench::bench(solution, &input, args.warmup, args.iterations) {
+            Ok(report) => report,
+            Err(e) => {
+                eprintln!("{}: {}", solution, e);
+                return ExitCode::FAILURE;
+            }
+        };
+
+        if !args.json {
+            println!("{}\n", report);
+        }
+
+        reports.push(report);
+    }
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&reports).unwrap());
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn main() -> ExitCode {
+    let args = match Args::parse(env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    if args.command == Command::Help {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    // A regression check covers every year unless told otherwise
+    let year = match args.command {
+        Command::Check => args.year,
+        _ => args.year.or_else(|| registry::years().last().copied()),
+    };
+    let solutions: Vec<_> = registry::find(year, args.day)
+        .filter(|s| args.part.is_none_or(|part| s.part == part))
+        .filter(|s| args.implementation.as_ref().is_none_or(|name| s.is_impl(name)))
+        .filter(|s| !s.baseline || matches!(args.command, Command::Bench | Command::List))
+        .collect();
+
+    if solutions.is_empty() {
+        eprintln!("error: no solutions match the selection");
+        return ExitCode::FAILURE;
+    }
+
+    match args.command {
+        Command::Run => {
+            println!("Advent of code {}", solutions[0].year);
+            run(&args, &solutions)
+        }
+        Command::List => {
+            for solution in &solutions {
+                println!("{} {}", solution.year, solution);
+            }
+
+            ExitCode::SUCCESS
+        }
+        Command::Check => check(&args, &solutions),
+        Command::Bench => bench(&args, &solutions),
+        Command::Help => unreachable!(),
+    }
+}
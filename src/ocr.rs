@@ -0,0 +1,224 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+/// A fixed-height font of capital letters, drawn with `#` and `.`.
+pub struct Font {
+    pub height: usize,
+    glyphs: &'static [(char, &'static [&'static str])],
+}
+
+/// The 4x6 font used by most years, ie 2016 day 8 and 2022 day 10
+pub static FONT_4X6: Font = Font {
+    height: 6,
+    glyphs: &[
+        ('A', &[".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+        ('B', &["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+        ('C', &[".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+        ('E', &["####", "#...", "###.", "#...", "#...", "####"]),
+        ('F', &["####", "#...", "###.", "#...", "#...", "#..."]),
+        ('G', &[".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+        ('H', &["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+        ('I', &["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+        ('J', &["..##", "...#", "...#", "...#", "#..#", ".##."]),
+        ('K', &["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+        ('L', &["#...", "#...", "#...", "#...", "#...", "####"]),
+        ('O', &[".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('P', &["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+        ('R', &["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+        ('S', &[".###", "#...", "#...", ".##.", "...#", "###."]),
+        ('U', &["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+        ('Y', &["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+        ('Z', &["####", "...#", "..#.", ".#..", "#...", "####"]),
+    ],
+};
+
+/// The 6x10 font used by older years, ie 2018 day 10
+pub static FONT_6X10: Font = Font {
+    height: 10,
+    glyphs: &[
+        ('A', &["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+        ('B', &["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+        ('C', &[".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+        ('E', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+        ('F', &["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+        ('G', &[".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+        ('H', &["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+        ('J', &["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+        ('K', &["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+        ('L', &["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+        ('N', &["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+        ('P', &["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+        ('R', &["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+        ('X', &["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+        ('Z', &["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+    ],
+};
+
+static FONTS: &[&Font] = &[&FONT_4X6, &FONT_6X10];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum OcrError {
+    /// No font is this many rows tall
+    UnknownFont { height: usize },
+    /// The `index`th glyph, starting at `column`, isn't a letter of the font
+    UnknownGlyph {
+        index: usize,
+        column: usize,
+        bitmap: String,
+    },
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            OcrError::UnknownFont { height } => write!(f, "no font is {} pixels tall", height),
+            OcrError::UnknownGlyph {
+                index,
+                column,
+                bitmap,
+            } => write!(
+                f,
+                "unknown glyph {} at column {}:\n{}",
+                index, column, bitmap
+            ),
+        }
+    }
+}
+
+impl Error for OcrError {}
+
+impl Font {
+    fn find(&self, glyph: &[String]) -> Option<char> {
+        self.glyphs
+            .iter()
+            .find(|(_, rows)| rows.iter().eq(glyph.iter()))
+            .map(|&(letter, _)| letter)
+    }
+}
+
+/// Reads the capital letters drawn in a bitmap of lit pixels.
+///
+/// Blank rows around the text are ignored and the font is picked by the
+/// height of what's left. Letters are split on blank columns, so they may
+/// be of varying width.
+pub fn recognize(rows: &[Vec<bool>]) -> Result<String, OcrError> {
+    let is_blank = |row: &&Vec<bool>| !row.contains(&true);
+    let top = rows.iter().position(|row| !is_blank(&row)).unwrap_or(0);
+    let bottom = rows.iter().rposition(|row| !is_blank(&row)).map_or(0, |i| i + 1);
+    let rows = &rows[top..bottom.max(top)];
+    let font = FONTS
+        .iter()
+        .find(|font| font.height == rows.len())
+        .ok_or(OcrError::UnknownFont { height: rows.len() })?;
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let is_lit = |x: usize| rows.iter().any(|row| row.get(x) == Some(&true));
+    let mut text = String::new();
+    let mut x = 0;
+
+    while x < width {
+        if !is_lit(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+
+        while x < width && is_lit(x) {
+            x += 1;
+        }
+
+        let glyph: Vec<String> = rows
+            .iter()
+            .map(|row| {
+                (start..x)
+                    .map(|x| if row.get(x) == Some(&true) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        let letter = font.find(&glyph).ok_or_else(|| OcrError::UnknownGlyph {
+            index: text.chars().count(),
+            column: start,
+            bitmap: glyph.join("\n"),
+        })?;
+
+        text.push(letter);
+    }
+
+    Ok(text)
+}
+
+#[cfg(test)]
+fn bitmap(image: &str) -> Vec<Vec<bool>> {
+    image
+        .lines()
+        .map(|line| line.chars().map(|ch| ch == '#').collect())
+        .collect()
+}
+
+#[test]
+fn test_recognize_4x6() {
+    let image = "
+###..#....###...##..####.###...##..#....
+#..#.#....#..#.#..#.#....#..#.#..#.#....
+#..#.#....#..#.#..#.###..###..#....#....
+###..#....###..####.#....#..#.#....#....
+#....#....#....#..#.#....#..#.#..#.#....
+#....####.#....#..#.#....###...##..####.";
+
+    assert_eq!(recognize(&bitmap(image)), Ok("PLPAFBCL".into()));
+
+    let image = "
+#...#.###.
+#...#..#..
+.#.#...#..
+..#....#..
+..#....#..
+..#...###.";
+
+    assert_eq!(recognize(&bitmap(image)), Ok("YI".into()));
+}
+
+#[test]
+fn test_recognize_6x10() {
+    // As the 2018 day 10 message reads once the points line up, with a
+    // blank border left around it
+    let image = "
+................................................
+.#####....####......###..#....#..#....#..#....#.
+.#....#..#....#......#...#...#...##...#..#....#.
+.#....#..#...........#...#..#....##...#...#..#..
+.#....#..#...........#...#.#.....#.#..#...#..#..
+.#####...#...........#...##......#.#..#....##...
+.#..#....#..###......#...##......#..#.#....##...
+.#...#...#....#......#...#.#.....#..#.#...#..#..
+.#...#...#....#..#...#...#..#....#...##...#..#..
+.#....#..#...##..#...#...#...#...#...##..#....#.
+.#....#...###.#...###....#....#..#....#..#....#.
+................................................";
+
+    assert_eq!(recognize(&bitmap(image)), Ok("RGJKNX".into()));
+}
+
+#[test]
+fn test_recognize_unknown() {
+    let image = "
+.##..#..#
+#..#.##.#
+#..#.#.##
+####.#..#
+#..#.#..#
+#..#.#..#";
+
+    assert_eq!(
+        recognize(&bitmap(image)),
+        Err(OcrError::UnknownGlyph {
+            index: 1,
+            column: 5,
+            bitmap: "#..#\n##.#\n#.##\n#..#\n#..#\n#..#".into(),
+        })
+    );
+    assert_eq!(
+        recognize(&bitmap("#\n#\n#")),
+        Err(OcrError::UnknownFont { height: 3 })
+    );
+}
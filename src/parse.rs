@@ -0,0 +1,250 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+#[cfg(test)]
+use aoc_derive::FromLine;
+
+/// A malformed piece of puzzle input, located by line and column (both 1-based).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The offending text
+    pub text: String,
+    pub message: String,
+    /// The whole input line the offending text was found on
+    pub source_line: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let gutter = self.line.to_string().len();
+
+        writeln!(f, "line {}, column {}: {}", self.line, self.column, self.message)?;
+        writeln!(f, "{:gutter$} |", "")?;
+        writeln!(f, "{} | {}", self.line, self.source_line)?;
+        write!(
+            f,
+            "{:gutter$} | {:pad$}{}",
+            "",
+            "",
+            "^".repeat(self.text.chars().count().max(1)),
+            pad = self.column - 1,
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// The input a parser is working on. Used to locate errors in slices of it.
+#[derive(Clone, Copy, Debug)]
+pub struct Source<'a> {
+    input: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Source { input }
+    }
+
+    /// Builds an error pointing at `at`, which should be a slice of the input.
+    /// Text that isn't part of the input is reported at the start of it.
+    pub fn error(&self, at: &str, message: impl Into<String>) -> ParseError {
+        let start = self.input.as_ptr() as usize;
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + at.len() <= self.input.len())
+            .unwrap_or(0);
+        let line_start = self.input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = self.input[offset..]
+            .find('\n')
+            .map_or(self.input.len(), |i| offset + i);
+
+        ParseError {
+            line: self.input[..offset].matches('\n').count() + 1,
+            column: self.input[line_start..offset].chars().count() + 1,
+            text: at.to_owned(),
+            message: message.into(),
+            source_line: self.input[line_start..line_end].to_owned(),
+        }
+    }
+
+    /// Parses a slice of the input, ie a number.
+    pub fn parse<T>(&self, s: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        s.parse()
+            .map_err(|e| self.error(s, format!("failed to parse `{}`: {}", s, e)))
+    }
+
+    /// Splits a slice of the input on the first occurrence of `delimiter`.
+    pub fn split_once(&self, s: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error(s, format!("expected `{}`", delimiter)))
+    }
+
+    /// Takes `prefix` off the start of a slice of the input.
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format!("expected `{}`", prefix)))
+    }
+
+    /// Takes `suffix` off the end of a slice of the input.
+    pub fn strip_suffix(&self, s: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+        s.strip_suffix(suffix)
+            .ok_or_else(|| self.error(s, format!("expected `{}` at the end", suffix)))
+    }
+
+    /// Checks nothing is left of a slice of the input.
+    pub fn end(&self, rest: &str) -> Result<(), ParseError> {
+        match rest.is_empty() {
+            true => Ok(()),
+            false => Err(self.error(rest, format!("unexpected `{}`", rest))),
+        }
+    }
+
+    /// Parses every line of a slice of the input.
+    pub fn lines<T: FromLine>(&self, s: &'a str) -> Result<Vec<T>, ParseError> {
+        s.lines().map(|line| T::from_line(self, line)).collect()
+    }
+
+    /// Builds an error for the unexpected character at byte `index` of `s`.
+    pub fn unexpected(&self, s: &str, index: usize) -> ParseError {
+        let rest = &s[index..];
+
+        match rest.chars().next() {
+            Some(ch) => self.error(&rest[..ch.len_utf8()], format!("unexpected `{}`", ch)),
+            None => self.error(rest, "unexpected end of line"),
+        }
+    }
+
+    /// Converts the decimal digit at byte `index` of `s`.
+    pub fn digit(&self, s: &str, index: usize) -> Result<u32, ParseError> {
+        s[index..]
+            .chars()
+            .next()
+            .and_then(|ch| ch.to_digit(10))
+            .ok_or_else(|| ParseError {
+                message: "expected a digit".into(),
+                ..self.unexpected(s, index)
+            })
+    }
+}
+
+/// Types parsed from a single line of input, usually derived with a pattern,
+/// ie `#[derive(FromLine)] #[pattern("{start}-{end}")]`
+pub trait FromLine: Sized {
+    /// Parses `line`, a slice of `source`'s input, so errors point into it.
+    fn from_line<'a>(source: &Source<'a>, line: &'a str) -> Result<Self, ParseError>;
+}
+
+#[test]
+fn test_parse_error_location() {
+    let input = "3-5\n10-1x\n16-20";
+    let source = Source::new(input);
+    let line = input.lines().nth(1).unwrap();
+    let (start, end) = source.split_once(line, "-").unwrap();
+
+    assert_eq!(source.parse::<u64>(start), Ok(10));
+
+    let err = source.parse::<u64>(end).unwrap_err();
+
+    assert_eq!((err.line, err.column), (2, 4));
+    assert_eq!(err.text, "1x");
+    assert_eq!(err.source_line, "10-1x");
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 4: failed to parse `1x`: invalid digit found in string\n  |\n2 | 10-1x\n  |    ^^"
+    );
+
+    let err = source.split_once(input.lines().last().unwrap(), ",").unwrap_err();
+
+    assert_eq!((err.line, err.column, err.text.as_str()), (3, 1, "16-20"));
+}
+
+#[test]
+fn test_parse_error_digit() {
+    let input = "12\n3a4";
+    let source = Source::new(input);
+    let line = input.lines().nth(1).unwrap();
+
+    assert_eq!(source.digit(line, 0), Ok(3));
+
+    let err = source.digit(line, 1).unwrap_err();
+
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, 2, "a"));
+    assert_eq!(err.message, "expected a digit");
+
+    let err = source.unexpected(line, 3);
+
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, 4, ""));
+    assert_eq!(err.message, "unexpected end of line");
+}
+
+#[cfg(test)]
+#[derive(Debug, PartialEq, FromLine)]
+#[pattern("{start}-{end}")]
+struct Range {
+    start: u64,
+    end: i32,
+}
+
+#[cfg(test)]
+#[derive(Debug, PartialEq, FromLine)]
+enum Instruction {
+    #[pattern("noop")]
+    Noop,
+    #[pattern("addx {0}")]
+    Addx(i32),
+    #[pattern("move {count} from {{{from}}} to {to}!")]
+    Move { count: u8, from: char, to: String },
+}
+
+#[test]
+fn test_from_line_struct() {
+    assert_eq!("3-5".parse(), Ok(Range { start: 3, end: 5 }));
+    // Only the first `-` splits, so the end can still be negative
+    assert_eq!("16--20".parse(), Ok(Range { start: 16, end: -20 }));
+
+    let input = "3-5\n10+12\n7-x";
+    let source = Source::new(input);
+    let err = source.lines::<Range>(input).unwrap_err();
+
+    assert_eq!((err.line, err.column, err.message.as_str()), (2, 1, "expected `-`"));
+
+    let err = source.lines::<Range>(&input[10..]).unwrap_err();
+
+    assert_eq!((err.line, err.column, err.text.as_str()), (3, 3, "x"));
+}
+
+#[test]
+fn test_from_line_enum() {
+    assert_eq!("noop".parse(), Ok(Instruction::Noop));
+    assert_eq!("addx -7".parse(), Ok(Instruction::Addx(-7)));
+    assert_eq!(
+        "move 3 from {a} to stack 2!".parse(),
+        Ok(Instruction::Move {
+            count: 3,
+            from: 'a',
+            to: "stack 2".to_owned()
+        })
+    );
+
+    let err = "noop 1".parse::<Instruction>().unwrap_err();
+
+    assert_eq!((err.column, err.message.as_str()), (5, "unexpected ` 1`"));
+
+    let err = "jmp 4".parse::<Instruction>().unwrap_err();
+
+    assert_eq!(
+        err.message,
+        "expected `noop`, `addx {0}` or `move {count} from {{{from}}} to {to}!`"
+    );
+
+    let err = "move 3 from {a} to 2".parse::<Instruction>().unwrap_err();
+
+    assert_eq!(err.message, "expected `!` at the end");
+}
@@ -0,0 +1,109 @@
+use std::ops::{Add, Sub};
+
+use crate::grid::{Grid, Pos};
+
+/// A square of a grid, found by `PrefixSum2D::best_window`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Window<T> {
+    /// The top left corner
+    pub pos: Pos,
+    pub size: usize,
+    pub sum: T,
+}
+
+/// A summed-area table: the sum of any rectangle of a grid in O(1).
+#[derive(Clone, Debug)]
+pub struct PrefixSum2D<T> {
+    /// `sums[(x, y)]` is the sum of every cell above and left of `(x, y)` in
+    /// the original grid, so the table is one wider and taller than it
+    sums: Grid<T>,
+}
+
+impl<T> PrefixSum2D<T>
+where
+    T: Copy + Default + Ord + Add<Output = T> + Sub<Output = T>,
+{
+    pub fn new(grid: &Grid<T>) -> Self {
+        let mut sums = Grid::from_fn(grid.width() + 1, grid.height() + 1, |_| T::default());
+
+        for y in 0..grid.height() {
+            for x in 0..grid.width() {
+                sums[(x + 1, y + 1)] =
+                    grid[(x, y)] + sums[(x, y + 1)] + sums[(x + 1, y)] - sums[(x, y)];
+            }
+        }
+
+        PrefixSum2D { sums }
+    }
+
+    pub fn width(&self) -> usize {
+        self.sums.width() - 1
+    }
+
+    pub fn height(&self) -> usize {
+        self.sums.height() - 1
+    }
+
+    /// The sum of the `width` by `height` rectangle with its top left corner at `(x, y)`.
+    pub fn sum(&self, (x, y): Pos, width: usize, height: usize) -> T {
+        let (right, bottom) = (x + width, y + height);
+
+        self.sums[(right, bottom)] - self.sums[(x, bottom)] - self.sums[(right, y)]
+            + self.sums[(x, y)]
+    }
+
+    /// The `size` by `size` square with the largest sum. Ties go to the first
+    /// in reading order.
+    pub fn best_window(&self, size: usize) -> Option<Window<T>> {
+        if size == 0 || size > self.width() || size > self.height() {
+            return None;
+        }
+
+        let mut best: Option<Window<T>> = None;
+
+        for y in 0..=self.height() - size {
+            for x in 0..=self.width() - size {
+                let sum = self.sum((x, y), size, size);
+
+                if best.is_none_or(|best| sum > best.sum) {
+                    best = Some(Window {
+                        pos: (x, y),
+                        size,
+                        sum,
+                    });
+                }
+            }
+        }
+
+        best
+    }
+
+    /// The square of any size with the largest sum. Ties go to the smallest,
+    /// then the first in reading order.
+    pub fn best_window_of_any_size(&self) -> Option<Window<T>> {
+        (1..=self.width().min(self.height()))
+            .filter_map(|size| self.best_window(size))
+            .reduce(|best, window| if window.sum > best.sum { window } else { best })
+    }
+}
+
+#[test]
+fn test_prefix_sum() {
+    let grid = Grid::new(3, 3, vec![1, 2, -3, 4, 5, 6, -7, 8, 9]);
+    let sums = PrefixSum2D::new(&grid);
+
+    assert_eq!(sums.sum((0, 0), 3, 3), 25);
+    assert_eq!(sums.sum((1, 1), 2, 2), 28);
+    assert_eq!(sums.sum((2, 0), 1, 3), 12);
+    assert_eq!(sums.sum((1, 1), 0, 2), 0);
+    assert_eq!(
+        sums.best_window(2),
+        Some(Window {
+            pos: (1, 1),
+            size: 2,
+            sum: 28
+        })
+    );
+    assert_eq!(sums.best_window(4), None);
+    assert_eq!(sums.best_window_of_any_size().map(|w| (w.pos, w.size)), Some(((1, 1), 2)));
+}
@@ -0,0 +1,238 @@
+use std::cmp::Ordering;
+use std::ops::RangeInclusive;
+
+/// Integers a `RangeSet` can hold
+pub trait Element: Copy + Ord {
+    fn checked_succ(self) -> Option<Self>;
+    fn checked_pred(self) -> Option<Self>;
+    /// The number of values in `start..=end`, with `start <= end`
+    fn count(start: Self, end: Self) -> u128;
+}
+
+macro_rules! element {
+    ($($int:ty),*) => {
+        $(
+            impl Element for $int {
+                fn checked_succ(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn checked_pred(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count(start: Self, end: Self) -> u128 {
+                    (end as i128 - start as i128) as u128 + 1
+                }
+            }
+        )*
+    };
+}
+
+element!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// A set of integers stored as sorted, disjoint inclusive spans. Overlapping
+/// and adjacent spans are merged as they're inserted.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RangeSet<T> {
+    spans: Vec<(T, T)>,
+}
+
+impl<T: Element> RangeSet<T> {
+    pub fn new() -> Self {
+        RangeSet { spans: Vec::new() }
+    }
+
+    /// Adds every value in `range`. Empty ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+
+        if start > end {
+            return;
+        }
+
+        // Spans that end before `start` without touching it stay as they are,
+        // as do spans that start after `end` without touching it
+        let lo = self
+            .spans
+            .partition_point(|&(_, e)| e.checked_succ().is_some_and(|after| after < start));
+        let hi = self
+            .spans
+            .partition_point(|&(s, _)| end.checked_succ().is_none_or(|after| s <= after));
+
+        if lo < hi {
+            start = start.min(self.spans[lo].0);
+            end = end.max(self.spans[hi - 1].1);
+        }
+
+        self.spans.splice(lo..hi, [(start, end)]);
+    }
+
+    /// Takes out every value in `range`, splitting spans it lands inside of.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+
+        if start > end {
+            return;
+        }
+
+        let lo = self.spans.partition_point(|&(_, e)| e < start);
+        let hi = self.spans.partition_point(|&(s, _)| s <= end);
+
+        if lo >= hi {
+            return;
+        }
+
+        let mut kept = Vec::with_capacity(2);
+
+        if let Some(before) = start.checked_pred().filter(|&before| self.spans[lo].0 <= before) {
+            kept.push((self.spans[lo].0, before));
+        }
+
+        if let Some(after) = end.checked_succ().filter(|&after| after <= self.spans[hi - 1].1) {
+            kept.push((after, self.spans[hi - 1].1));
+        }
+
+        self.spans.splice(lo..hi, kept);
+    }
+
+    /// Whether a value is in the set, found by binary search.
+    pub fn contains(&self, value: T) -> bool {
+        self.spans
+            .binary_search_by(|&(start, end)| {
+                if end < value {
+                    Ordering::Less
+                } else if start > value {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+
+        for range in other.iter() {
+            union.insert(range);
+        }
+
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut spans = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(&(lhs_start, lhs_end)), Some(&(rhs_start, rhs_end))) =
+            (self.spans.get(i), other.spans.get(j))
+        {
+            let (start, end) = (lhs_start.max(rhs_start), lhs_end.min(rhs_end));
+
+            if start <= end {
+                spans.push((start, end));
+            }
+
+            // Move past whichever span ends first
+            if lhs_end < rhs_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        RangeSet { spans }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+
+        for range in other.iter() {
+            difference.remove(range);
+        }
+
+        difference
+    }
+
+    /// The number of values in the set
+    pub fn len(&self) -> u128 {
+        self.spans.iter().map(|&(start, end)| T::count(start, end)).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// The disjoint spans making up the set, in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.spans.iter().map(|&(start, end)| start..=end)
+    }
+}
+
+impl<T: Element> FromIterator<RangeInclusive<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+
+        for range in iter {
+            set.insert(range);
+        }
+
+        set
+    }
+}
+
+#[test]
+fn test_range_set_insert() {
+    let mut set = RangeSet::new();
+
+    set.insert(10..=14);
+    set.insert(3..=5);
+    set.insert(16..=20);
+    set.insert(12..=18);
+    set.insert(6..=6);
+    set.insert(RangeInclusive::new(9, 8));
+
+    assert_eq!(set.iter().collect::<Vec<_>>(), [3..=6, 10..=20]);
+    assert_eq!(set.len(), 15);
+    assert!(set.contains(6) && set.contains(10) && set.contains(20));
+    assert!(!set.contains(2) && !set.contains(7) && !set.contains(21));
+
+    set.insert(0..=u8::MAX);
+
+    assert_eq!(set.iter().collect::<Vec<_>>(), [0..=u8::MAX]);
+    assert_eq!(set.len(), 256);
+}
+
+#[test]
+fn test_range_set_remove() {
+    let mut set: RangeSet<i32> = [-10..=10, 20..=30].into_iter().collect();
+
+    set.remove(-2..=2);
+    set.remove(8..=22);
+    set.remove(30..=40);
+    set.remove(100..=200);
+
+    assert_eq!(set.iter().collect::<Vec<_>>(), [-10..=-3, 3..=7, 23..=29]);
+
+    set.remove(i32::MIN..=i32::MAX);
+
+    assert!(set.is_empty());
+}
+
+#[test]
+fn test_range_set_ops() {
+    let lhs: RangeSet<u64> = [1..=5, 10..=15].into_iter().collect();
+    let rhs: RangeSet<u64> = [4..=11, 15..=20].into_iter().collect();
+
+    assert_eq!(lhs.union(&rhs).iter().collect::<Vec<_>>(), [1..=20]);
+    assert_eq!(
+        lhs.intersection(&rhs).iter().collect::<Vec<_>>(),
+        [4..=5, 10..=11, 15..=15]
+    );
+    assert_eq!(
+        lhs.difference(&rhs).iter().collect::<Vec<_>>(),
+        [1..=3, 12..=14]
+    );
+    assert_eq!(rhs.difference(&lhs).len(), 4 + 5);
+}
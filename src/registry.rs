@@ -0,0 +1,69 @@
+use std::any::Any;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::answer::Answer;
+
+/// The type-erased output of a day's generator
+pub type Generated = Box<dyn Any>;
+/// Why a generator or solution failed, ie a `ParseError` for malformed input
+pub type SolveError = Box<dyn Error>;
+
+/// A single `#[aoc]` function, registered under the year of its module.
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Alternate implementation name, ie `Chars`
+    pub name: Option<&'static str>,
+    /// Kept only for `aoc bench` to time the others against, so it isn't run
+    /// or checked
+    pub baseline: bool,
+    /// Runs the day's generator, or copies the raw input when there is none
+    pub generate: fn(&str) -> Result<Generated, SolveError>,
+    /// Solves the part from the output of `generate`
+    pub solve: fn(&dyn Any) -> Result<Answer, SolveError>,
+}
+
+impl Solution {
+    pub fn run(&self, input: &str) -> Result<Answer, SolveError> {
+        (self.solve)(&*(self.generate)(input)?)
+    }
+
+    /// Whether this is the given alternate implementation, ignoring case.
+    pub fn is_impl(&self, name: &str) -> bool {
+        self.name.is_some_and(|n| n.eq_ignore_ascii_case(name))
+    }
+}
+
+impl Display for Solution {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Day {} - Part {}", self.day, self.part)?;
+
+        if let Some(name) = self.name {
+            write!(f, " - {}", name)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Every solution in the crate, ordered by year, day, part and name.
+// Parts that already return an `Answer` still go through `Answer::from`
+#[allow(clippy::useless_conversion)]
+pub static SOLUTIONS: &[Solution] = include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+/// All years with at least one solution, in ascending order.
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<_> = SOLUTIONS.iter().map(|s| s.year).collect();
+
+    years.dedup();
+    years
+}
+
+/// All solutions, optionally narrowed down to a single year and day.
+pub fn find(year: Option<u16>, day: Option<u8>) -> impl Iterator<Item = &'static Solution> {
+    SOLUTIONS.iter().filter(move |s| {
+        year.is_none_or(|year| s.year == year) && day.is_none_or(|day| s.day == day)
+    })
+}
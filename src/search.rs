@@ -0,0 +1,380 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// Something to search through, ie the open squares of a map.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    fn neighbours(&self, node: &Self::Node) -> impl Iterator<Item = Self::Node>;
+
+    /// The cost of moving between two neighbouring nodes. Ignored by `bfs`.
+    fn cost(&self, _from: &Self::Node, _to: &Self::Node) -> u64 {
+        1
+    }
+
+    /// Orders nodes reached at the same cost. Of those, the lesser is expanded
+    /// first, preferred as a parent and returned as the goal. Nodes that tie
+    /// here are taken in the order they were found.
+    fn tie_break(&self, _lhs: &Self::Node, _rhs: &Self::Node) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+/// A route from a start node to a goal, both included.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N> {
+    pub nodes: Vec<N>,
+    pub cost: u64,
+}
+
+impl<N> Path<N> {
+    pub fn start(&self) -> &N {
+        &self.nodes[0]
+    }
+
+    pub fn goal(&self) -> &N {
+        &self.nodes[self.nodes.len() - 1]
+    }
+
+    /// The number of moves taken, as opposed to their cost
+    pub fn len(&self) -> usize {
+        self.nodes.len() - 1
+    }
+
+    /// Whether the start is already the goal
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The node moved to first, unless the start is the goal
+    pub fn first_step(&self) -> Option<&N> {
+        self.nodes.get(1)
+    }
+}
+
+fn reconstruct<N: Clone + Eq + Hash>(parents: &HashMap<N, N>, goal: N, cost: u64) -> Path<N> {
+    let mut nodes = vec![goal];
+
+    while let Some(parent) = parents.get(&nodes[nodes.len() - 1]) {
+        nodes.push(parent.clone());
+    }
+
+    nodes.reverse();
+
+    Path { nodes, cost }
+}
+
+/// Finds the path with the fewest moves from `start` to a node satisfying
+/// `is_goal`.
+pub fn bfs<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    let mut parents = HashMap::new();
+    let mut seen = HashSet::from([start.clone()]);
+    let mut frontier = vec![start];
+    let mut depth = 0;
+
+    while !frontier.is_empty() {
+        // Stable, so nodes that tie stay in the order they were found
+        frontier.sort_by(|lhs, rhs| graph.tie_break(lhs, rhs));
+
+        if let Some(goal) = frontier.iter().find(|node| is_goal(node)) {
+            return Some(reconstruct(&parents, goal.clone(), depth));
+        }
+
+        let mut next = Vec::new();
+
+        for node in &frontier {
+            for neighbour in graph.neighbours(node) {
+                if seen.insert(neighbour.clone()) {
+                    parents.insert(neighbour.clone(), node.clone());
+                    next.push(neighbour);
+                }
+            }
+        }
+
+        frontier = next;
+        depth += 1;
+    }
+
+    None
+}
+
+/// How far a node is from the nearest start, found by `distance_field`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reached<N> {
+    /// The number of moves from `source`
+    pub distance: usize,
+    /// The nearest start. Of several as near, the least by `Graph::tie_break`
+    pub source: N,
+}
+
+/// Floods outwards from every start at once, finding how far each reachable
+/// node is from the nearest of them. Walking back towards a node's source is
+/// a matter of stepping to any neighbour with the same source and a distance
+/// one less.
+pub fn distance_field<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+) -> HashMap<G::Node, Reached<G::Node>> {
+    let mut starts: Vec<_> = starts.into_iter().collect();
+
+    // Queued in tie break order, each level of the flood stays in order of
+    // source, so a node is always first reached from its least source
+    starts.sort_by(|lhs, rhs| graph.tie_break(lhs, rhs));
+
+    let mut field = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = field.entry(start.clone()) {
+            entry.insert(Reached {
+                distance: 0,
+                source: start.clone(),
+            });
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let Reached { distance, source } = field[&node].clone();
+
+        for neighbour in graph.neighbours(&node) {
+            if let Entry::Vacant(entry) = field.entry(neighbour.clone()) {
+                entry.insert(Reached {
+                    distance: distance + 1,
+                    source: source.clone(),
+                });
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    field
+}
+
+/// Finds the cheapest path from `start` to a node satisfying `is_goal`.
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    astar(graph, start, is_goal, |_| 0)
+}
+
+struct Queued<'g, G: Graph> {
+    graph: &'g G,
+    /// Cost so far plus the heuristic
+    estimate: u64,
+    /// Breaks remaining ties in favour of nodes queued earlier
+    order: usize,
+    node: G::Node,
+}
+
+impl<G: Graph> Ord for Queued<'_, G> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed, as BinaryHeap pops the greatest
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.graph.tie_break(&other.node, &self.node))
+            .then_with(|| other.order.cmp(&self.order))
+    }
+}
+
+impl<G: Graph> PartialOrd for Queued<'_, G> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<G: Graph> PartialEq for Queued<'_, G> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<G: Graph> Eq for Queued<'_, G> {}
+
+/// Finds the cheapest path from `start` to a node satisfying `is_goal`,
+/// expanding the nodes `heuristic` estimates closest to a goal first. The
+/// heuristic must never overestimate the remaining cost.
+pub fn astar<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+    mut heuristic: impl FnMut(&G::Node) -> u64,
+) -> Option<Path<G::Node>> {
+    let mut parents: HashMap<G::Node, G::Node> = HashMap::new();
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut done = HashSet::new();
+    let mut open = BinaryHeap::new();
+    let mut order = 0;
+
+    open.push(Queued {
+        graph,
+        estimate: heuristic(&start),
+        order,
+        node: start,
+    });
+
+    while let Some(Queued { node, .. }) = open.pop() {
+        if !done.insert(node.clone()) {
+            continue;
+        }
+
+        let cost = costs[&node];
+
+        if is_goal(&node) {
+            return Some(reconstruct(&parents, node, cost));
+        }
+
+        for neighbour in graph.neighbours(&node) {
+            if done.contains(&neighbour) {
+                continue;
+            }
+
+            let new_cost = cost + graph.cost(&node, &neighbour);
+            let improved = match costs.entry(neighbour.clone()) {
+                Entry::Vacant(entry) => {
+                    entry.insert(new_cost);
+                    true
+                }
+                Entry::Occupied(mut entry) if new_cost < *entry.get() => {
+                    entry.insert(new_cost);
+                    true
+                }
+                Entry::Occupied(entry) => {
+                    // Same cost by way of a preferred parent
+                    if new_cost == *entry.get()
+                        && graph.tie_break(&node, &parents[&neighbour]) == Ordering::Less
+                    {
+                        parents.insert(neighbour.clone(), node.clone());
+                    }
+
+                    false
+                }
+            };
+
+            if improved {
+                order += 1;
+                parents.insert(neighbour.clone(), node.clone());
+                open.push(Queued {
+                    graph,
+                    estimate: new_cost + heuristic(&neighbour),
+                    order,
+                    node: neighbour,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+struct TestMap {
+    grid: crate::grid::Grid<u8>,
+}
+
+#[cfg(test)]
+impl Graph for TestMap {
+    type Node = crate::grid::Pos;
+
+    fn neighbours(&self, &pos: &Self::Node) -> impl Iterator<Item = Self::Node> {
+        self.grid.neighbours4(pos).filter(|&pos| self.grid[pos] != b'#')
+    }
+
+    fn cost(&self, _from: &Self::Node, &to: &Self::Node) -> u64 {
+        match self.grid[to] {
+            digit @ b'1'..=b'9' => (digit - b'0') as u64,
+            _ => 1,
+        }
+    }
+
+    fn tie_break(&self, &(lhs_x, lhs_y): &Self::Node, &(rhs_x, rhs_y): &Self::Node) -> Ordering {
+        // Reading order
+        (lhs_y, lhs_x).cmp(&(rhs_y, rhs_x))
+    }
+}
+
+#[cfg(test)]
+fn test_map(map: &str) -> TestMap {
+    TestMap {
+        grid: crate::grid::Grid::parse(map, |ch| Some(ch as u8)).unwrap(),
+    }
+}
+
+#[test]
+fn test_bfs() {
+    let map = test_map(
+        "\
+S...
+.##.
+...G",
+    );
+    let path = bfs(&map, (0, 0), |&pos| pos == (3, 2)).unwrap();
+
+    // Both ways round are as short, reading order prefers going right first
+    assert_eq!(path.nodes, [(0, 0), (1, 0), (2, 0), (3, 0), (3, 1), (3, 2)]);
+    assert_eq!((path.len(), path.cost), (5, 5));
+    assert_eq!(path.first_step(), Some(&(1, 0)));
+
+    // The nearest goals tie, reading order picks the first
+    let path = bfs(&map, (0, 2), |&pos| pos == (0, 0) || pos == (2, 2)).unwrap();
+
+    assert_eq!(path.goal(), &(0, 0));
+
+    let walled = test_map("S#G");
+
+    assert_eq!(bfs(&walled, (0, 0), |&pos| pos == (2, 0)), None);
+
+    let path = bfs(&walled, (0, 0), |&pos| pos == (0, 0)).unwrap();
+
+    assert!(path.is_empty());
+    assert_eq!(path.first_step(), None);
+}
+
+#[test]
+fn test_distance_field() {
+    let map = test_map(
+        "\
+.....
+.###.
+.....",
+    );
+    let field = distance_field(&map, [(4, 2), (0, 2)]);
+    let reached = |pos| field.get(&pos).map(|r: &Reached<_>| (r.distance, r.source));
+
+    assert_eq!(reached((0, 0)), Some((2, (0, 2))));
+    assert_eq!(reached((4, 0)), Some((2, (4, 2))));
+    // As near to both, so the first in reading order wins
+    assert_eq!(reached((2, 0)), Some((4, (0, 2))));
+    assert_eq!(reached((2, 2)), Some((2, (0, 2))));
+    assert_eq!(reached((1, 1)), None);
+    assert_eq!(field.len(), 12);
+}
+
+#[test]
+fn test_dijkstra_and_astar() {
+    let map = test_map(
+        "\
+S9..
+.##.
+....",
+    );
+    let goal = (3, 0);
+    let path = dijkstra(&map, (0, 0), |&pos| pos == goal).unwrap();
+
+    assert_eq!(path.cost, 7);
+    assert_eq!(path.first_step(), Some(&(0, 1)));
+
+    let manhattan = |&(x, y): &(usize, usize)| (x.abs_diff(goal.0) + y.abs_diff(goal.1)) as u64;
+
+    assert_eq!(astar(&map, (0, 0), |&pos| pos == goal, manhattan), Some(path));
+    assert_eq!(dijkstra(&test_map("S#."), (0, 0), |&pos| pos == (2, 0)), None);
+}
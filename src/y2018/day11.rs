@@ -0,0 +1,73 @@
+use aoc_derive::{aoc, aoc_generator};
+
+use crate::grid::Grid;
+use crate::parse::{ParseError, Source};
+use crate::prefix_sum::PrefixSum2D;
+
+const GRID_WIDTH: usize = 300;
+
+fn power_sums(grid: &Grid<FuelCell>) -> PrefixSum2D<i32> {
+    PrefixSum2D::new(&grid.map(|fuel_cell| fuel_cell.power_level as i32))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct FuelCell {
+    power_level: i16,
+}
+
+#[aoc_generator(day11)]
+pub fn input_generator(input: &str) -> Result<Grid<FuelCell>, ParseError> {
+    let grid_serial_number = Source::new(input).parse::<isize>(input)?;
+
+    Ok(Grid::from_fn(GRID_WIDTH, GRID_WIDTH, |(x, y)| {
+        let x = x as isize + 1;
+        let y = y as isize + 1;
+        let rack_id = x + 10;
+        let mut power_level = rack_id * y;
+
+        power_level += grid_serial_number;
+        power_level *= rack_id;
+        power_level /= 100;
+        power_level %= 10;
+        power_level -= 5;
+
+        FuelCell {
+            power_level: power_level as i16,
+        }
+    }))
+}
+
+#[aoc(day11, part1, Chars)]
+pub fn part1_chars(grid: &Grid<FuelCell>) -> (usize, usize) {
+    let (x, y) = power_sums(grid).best_window(3).unwrap().pos;
+
+    // Fuel cells are numbered from 1
+    (x + 1, y + 1)
+}
+
+#[aoc(day11, part2, Chars)]
+pub fn part2_chars(grid: &Grid<FuelCell>) -> (usize, usize, usize) {
+    let window = power_sums(grid).best_window_of_any_size().unwrap();
+    let (x, y) = window.pos;
+
+    (x + 1, y + 1, window.size)
+}
+
+#[test]
+fn test_power_cell_examples() {
+    let grid = input_generator("57").unwrap();
+
+    assert_eq!(grid[(121, 78)].power_level, -5);
+
+    let grid = input_generator("39").unwrap();
+
+    assert_eq!(grid[(216, 195)].power_level, 0);
+
+    let grid = input_generator("71").unwrap();
+
+    assert_eq!(grid[(100, 152)].power_level, 4);
+
+    let grid = input_generator("18").unwrap();
+
+    assert_eq!(power_sums(&grid).sum((32, 44), 3, 3), 29);
+}
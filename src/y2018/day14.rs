@@ -1,27 +1,17 @@
-extern crate subslice;
-
-use std::fmt::{self, Display, Formatter};
+use aoc_derive::{aoc, aoc_generator};
 use subslice::bmh;
 
-#[aoc_generator(day14)]
-pub fn input_generator(input: &str) -> (Vec<u8>, usize) {
-    let digits = input.chars()
-        .map(|c| c.to_digit(10).unwrap() as u8)
-        .collect();
-    let num = input.parse::<usize>().unwrap();
-
-    (digits, num)
-}
-
-#[derive(Debug, PartialEq, Eq)]
-pub struct DisplayableArray10([u8; 10]);
+use crate::parse::{ParseError, Source};
 
-impl Display for DisplayableArray10 {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{}{}{}{}{}{}{}{}{}{}",
-               self.0[0], self.0[1], self.0[2], self.0[3], self.0[4],
-               self.0[5], self.0[6], self.0[7], self.0[8], self.0[9])
-    }
+#[aoc_generator(day14)]
+pub fn input_generator(input: &str) -> Result<(Vec<u8>, usize), ParseError> {
+    let source = Source::new(input);
+    let digits = input.char_indices()
+        .map(|(i, _)| source.digit(input, i).map(|d| d as u8))
+        .collect::<Result<_, _>>()?;
+    let num = source.parse::<usize>(input)?;
+
+    Ok((digits, num))
 }
 
 fn calculate_scores(digits: &Vec<u8>) -> Vec<u8> {
@@ -33,7 +23,7 @@ fn calculate_scores(digits: &Vec<u8>) -> Vec<u8> {
     scores.push(7);
 
     while scores.len() <= digits.len() || &scores[scores.len() - digits.len()..] != digits.as_slice() {
-        let score = (scores[elf1_index] + scores[elf2_index]) as u8;
+        let score = scores[elf1_index] + scores[elf2_index];
         let (ldigit, rdigit) = if score > 9 {
             (1, Some(score - 10))
         } else {
@@ -68,20 +58,21 @@ fn calculate_scores(digits: &Vec<u8>) -> Vec<u8> {
 }
 
 #[aoc(day14, part1, Chars)]
-pub fn part1_chars((digits, num_recipes): &(Vec<u8>, usize)) -> DisplayableArray10 {
-    let scores = calculate_scores(&digits);
-    let mut array = [0; 10];
+pub fn part1_chars((digits, num_recipes): &(Vec<u8>, usize)) -> String {
+    let scores = calculate_scores(digits);
     let end_slice = num_recipes + 10;
 
-    array.copy_from_slice(&scores[*num_recipes..end_slice]);
-
-    DisplayableArray10(array)
+    // A string rather than a number, as the scores may start with a zero
+    scores[*num_recipes..end_slice]
+        .iter()
+        .map(|score| char::from(b'0' + score))
+        .collect()
 }
 
 #[aoc(day14, part2, Chars)]
 pub fn part2_chars((digits, _): &(Vec<u8>, usize)) -> usize {
-    let scores = calculate_scores(&digits);
-    let index = bmh::find(&scores, &digits).unwrap_or(0);
+    let scores = calculate_scores(digits);
+    let index = bmh::find(&scores, digits).unwrap_or(0);
 
     scores[..index].len()
 }
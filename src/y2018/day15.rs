@@ -0,0 +1,1308 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
+use std::mem::replace;
+use std::thread;
+
+use aoc_derive::{aoc, aoc_generator};
+
+use crate::grid::{Grid, Pos};
+use crate::parse::ParseError;
+use crate::registry::SolveError;
+use crate::search::{self, Graph, Path};
+
+/// Identifies a unit for its whole life, numbered in reading order of the
+/// starting map
+pub type UnitId = u16;
+
+/// A side of a battle, the index of its rules in `Rules::factions`
+pub type Faction = usize;
+
+/// The elves' faction under `Rules::standard`
+pub const ELVES: Faction = 0;
+
+/// The goblins' faction under `Rules::standard`
+pub const GOBLINS: Faction = 1;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FactionRules {
+    /// The character its units are drawn with on the map
+    pub symbol: char,
+    pub hp: u8,
+    pub attack: u8,
+    /// Units of factions with a lower priority take their turns first. Ties,
+    /// like every unit in the standard rules, go in reading order.
+    pub priority: u8,
+}
+
+/// Who fights in a battle and how
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rules {
+    pub factions: Vec<FactionRules>,
+}
+
+impl Rules {
+    /// Elves against goblins, each with 200 hp and 3 attack power
+    pub fn standard() -> Self {
+        let faction = |symbol| FactionRules {
+            symbol,
+            hp: 200,
+            attack: 3,
+            priority: 0,
+        };
+
+        Rules {
+            factions: vec![faction('E'), faction('G')],
+        }
+    }
+
+    pub fn faction_of(&self, symbol: char) -> Option<Faction> {
+        self.factions.iter().position(|faction| faction.symbol == symbol)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Tile {
+    Unit { faction: Faction, hp: u8, id: UnitId },
+    Open,
+    Wall,
+}
+
+impl Tile {
+    fn take_damage(&mut self, damage: u8) -> bool {
+        let Tile::Unit { hp, .. } = self else {
+            panic!("Attacking non sentient creature");
+        };
+
+        // Take damage or dies
+        if *hp > damage {
+            *hp -= damage;
+
+            false
+        } else {
+            *self = Tile::Open;
+
+            true
+        }
+    }
+
+    fn faction(&self) -> Option<Faction> {
+        match self {
+            Tile::Unit { faction, .. } => Some(*faction),
+            _ => None,
+        }
+    }
+
+    fn hp(&self) -> u8 {
+        match self {
+            Tile::Unit { hp, .. } => *hp,
+            _ => panic!("Tried getting hp on non npc tile"),
+        }
+    }
+
+    fn id(&self) -> UnitId {
+        match self {
+            Tile::Unit { id, .. } => *id,
+            _ => panic!("Tried getting id on non npc tile"),
+        }
+    }
+
+    fn is_enemy_of(&self, faction: Faction) -> bool {
+        self.faction().is_some_and(|other| other != faction)
+    }
+
+    #[cfg(test)]
+    fn is_elf(&self) -> bool {
+        self.faction() == Some(ELVES)
+    }
+
+    #[cfg(test)]
+    fn is_goblin(&self) -> bool {
+        self.faction() == Some(GOBLINS)
+    }
+}
+
+/// Something that happened during a battle
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+    Moved { unit: UnitId, from: Pos, to: Pos },
+    Attacked { attacker: UnitId, target: UnitId, damage: u8 },
+    /// The unit was killed where it stood
+    Died { unit: UnitId, at: Pos },
+    /// Every unit has had its turn. Battles end partway through a round, so
+    /// the last one doesn't get this.
+    RoundEnded { round: u8 },
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Event::Moved { unit, from, to } => {
+                write!(f, "#{} moved {:?} -> {:?}", unit, from, to)
+            }
+            Event::Attacked {
+                attacker,
+                target,
+                damage,
+            } => write!(f, "#{} hit #{} for {}", attacker, target, damage),
+            Event::Died { unit, at } => write!(f, "#{} died at {:?}", unit, at),
+            Event::RoundEnded { round } => write!(f, "round {} ended", round),
+        }
+    }
+}
+
+/// A recording of a battle's events
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Trace {
+    events: Vec<Event>,
+}
+
+impl Trace {
+    pub fn new() -> Self {
+        Trace { events: Vec::new() }
+    }
+
+    pub fn record(&mut self, event: Event) {
+        self.events.push(event);
+    }
+
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Only the events matching a predicate, ie a single unit's
+    pub fn filter(&self, mut predicate: impl FnMut(&Event) -> bool) -> Trace {
+        Trace {
+            events: self.events.iter().copied().filter(|event| predicate(event)).collect(),
+        }
+    }
+
+    /// Where this trace first strays from a reference one, if it does.
+    pub fn diff(&self, reference: &[Event]) -> Option<Mismatch> {
+        let len = self.events.len().max(reference.len());
+
+        (0..len)
+            .map(|index| Mismatch {
+                index,
+                actual: self.events.get(index).copied(),
+                expected: reference.get(index).copied(),
+            })
+            .find(|mismatch| mismatch.actual != mismatch.expected)
+    }
+}
+
+impl Display for Trace {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for event in &self.events {
+            writeln!(f, "{}", event)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The first difference between a trace and a reference one. An event is
+/// missing when one trace ends before the other.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    pub index: usize,
+    pub actual: Option<Event>,
+    pub expected: Option<Event>,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let show = |event: Option<Event>| event.map_or("nothing".to_owned(), |e| e.to_string());
+
+        write!(
+            f,
+            "event {}: expected {}, got {}",
+            self.index,
+            show(self.expected),
+            show(self.actual)
+        )
+    }
+}
+
+/// The open squares of a map
+struct Open<'a> {
+    map: &'a Map,
+}
+
+impl Graph for Open<'_> {
+    type Node = usize;
+
+    fn neighbours(&self, &index: &usize) -> impl Iterator<Item = usize> {
+        self.map.adjacent(index).filter(|&i| self.map.tiles[i] == Tile::Open)
+    }
+
+    /// Flat indices are in reading order
+    fn tie_break(&self, lhs: &usize, rhs: &usize) -> Ordering {
+        lhs.cmp(rhs)
+    }
+}
+
+/// How units find their way to their enemies
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Pathing {
+    /// One flood from every square in range of an enemy per move
+    DistanceField,
+    /// A search from each open neighbour to each enemy per move, much slower
+    /// and kept to check and time the distance field against
+    Pairwise,
+}
+
+/// The open squares of a map, plus the square of the unit being walked to
+struct Walkable<'a> {
+    map: &'a Map,
+    target: usize,
+}
+
+impl Graph for Walkable<'_> {
+    type Node = usize;
+
+    fn neighbours(&self, &index: &usize) -> impl Iterator<Item = usize> {
+        self.map
+            .adjacent(index)
+            .filter(|&i| i == self.target || self.map.tiles[i] == Tile::Open)
+    }
+
+    /// Flat indices are in reading order
+    fn tie_break(&self, lhs: &usize, rhs: &usize) -> Ordering {
+        lhs.cmp(rhs)
+    }
+}
+
+#[derive(Clone)]
+pub struct Map {
+    rules: Rules,
+    /// Where each faction's units are
+    units: Vec<HashSet<usize>>,
+    tiles: Grid<Tile>,
+    total_turns: u8,
+    pathing: Pathing,
+    /// Stops the battle as soon as a unit of this faction dies
+    abort_on_death: Option<Faction>,
+    aborted: bool,
+}
+
+impl Map {
+    /// Reads a map, with units drawn by their faction's symbol.
+    pub fn parse(input: &str, rules: Rules) -> Result<Self, ParseError> {
+        let mut next_id = 0;
+        let tiles = Grid::parse(input, |ch| match ch {
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Open),
+            _ => {
+                let faction = rules.faction_of(ch)?;
+
+                next_id += 1;
+
+                Some(Tile::Unit {
+                    faction,
+                    hp: rules.factions[faction].hp,
+                    id: next_id - 1,
+                })
+            }
+        })?;
+        let mut units = vec![HashSet::new(); rules.factions.len()];
+
+        for (i, tile) in tiles.iter().enumerate() {
+            if let Some(faction) = tile.faction() {
+                units[faction].insert(i);
+            }
+        }
+
+        Ok(Map {
+            rules,
+            units,
+            tiles,
+            total_turns: 0,
+            pathing: Pathing::DistanceField,
+            abort_on_death: None,
+            aborted: false,
+        })
+    }
+
+    pub fn rules_mut(&mut self) -> &mut Rules {
+        &mut self.rules
+    }
+
+    /// The last faction standing, once there is one
+    fn winner(&self) -> Option<Faction> {
+        let mut alive = (0..self.units.len()).filter(|&faction| !self.units[faction].is_empty());
+
+        match (alive.next(), alive.next()) {
+            (Some(faction), None) => Some(faction),
+            _ => None,
+        }
+    }
+
+    fn sum_total_hp(&self) -> u64 {
+        self.tiles
+            .iter()
+            .filter(|tile| tile.faction().is_some())
+            .map(|tile| tile.hp() as u64)
+            .sum()
+    }
+
+    /// The tiles next to a tile, in reading order
+    fn adjacent(&self, index: usize) -> impl Iterator<Item = usize> + '_ {
+        self.tiles
+            .neighbours4(self.tiles.pos_of(index))
+            .map(|pos| self.tiles.index_of(pos))
+    }
+
+    fn get_moves(&self, index: usize, open_only: bool) -> Vec<usize> {
+        self.adjacent(index)
+            .filter(|&tile_index| !open_only || self.tiles[tile_index] == Tile::Open)
+            .collect()
+    }
+
+    /// Pick the enemy with lowest hp in reading order
+    fn get_nearby_enemy(&self, index: usize, faction: Faction) -> Option<usize> {
+        self.adjacent(index)
+            .filter(|&tile_index| self.tiles[tile_index].is_enemy_of(faction))
+            .min_by_key(|&tile_index| self.tiles[tile_index].hp())
+    }
+
+    /// Has the unit at `index` hit the weakest enemy next to it, if there is one.
+    fn attack(&mut self, index: usize, on_event: &mut impl FnMut(Event)) -> bool {
+        let attacker = self.tiles[index];
+        let faction = attacker.faction().expect("only units attack");
+        let Some(enemy_index) = self.get_nearby_enemy(index, faction) else {
+            return false;
+        };
+        let enemy = self.tiles[enemy_index];
+        let damage = self.rules.factions[faction].attack;
+
+        on_event(Event::Attacked {
+            attacker: attacker.id(),
+            target: enemy.id(),
+            damage,
+        });
+
+        if self.tiles[enemy_index].take_damage(damage) {
+            self.units[enemy.faction().unwrap()].remove(&enemy_index);
+            self.aborted |= self.abort_on_death == enemy.faction();
+            on_event(Event::Died {
+                unit: enemy.id(),
+                at: self.tiles.pos_of(enemy_index),
+            });
+        }
+
+        true
+    }
+
+    /// The square the unit at `index` steps to on its way to the nearest
+    /// square in range of an enemy. Ties for nearest go to the first in
+    /// reading order, as do ties for the step towards it.
+    fn next_step(&self, index: usize, enemies: &[usize]) -> Option<usize> {
+        let in_range = enemies
+            .iter()
+            .flat_map(|&enemy| self.adjacent(enemy))
+            .filter(|&i| self.tiles[i] == Tile::Open);
+        let field = search::distance_field(&Open { map: self }, in_range);
+
+        // A neighbour closest to the chosen square is one closer than the
+        // unit is, and has it as its nearest
+        self.adjacent(index)
+            .filter_map(|i| field.get(&i).map(|reached| (reached.distance, reached.source, i)))
+            .min()
+            .map(|(_, _, step)| step)
+    }
+
+    /// Like `next_step`, but searching from every open neighbour to every
+    /// enemy separately. `part1_pairwise` runs the battle with it, so
+    /// `aoc bench --year 2018 --day 15 --part 1` times the two side by side.
+    fn next_step_pairwise(&self, index: usize, enemies: &[usize]) -> Option<usize> {
+        let possible_moves = self.get_moves(index, true);
+
+        // Paths end on the enemy, so the square before it is the one in range
+        let in_range = |path: &Path<usize>| path.nodes[path.len() - 1];
+        let mut path: Option<Path<usize>> = None;
+
+        for possible_move in possible_moves {
+            assert_eq!(self.tiles[possible_move], Tile::Open, "Possible move is not open");
+
+            for &enemy_pos in enemies {
+                let walkable = Walkable {
+                    map: self,
+                    target: enemy_pos,
+                };
+                let Some(new_path) = search::bfs(&walkable, possible_move, |&i| i == enemy_pos) else {
+                    continue;
+                };
+
+                // Nearest square in range first, then the first of those in reading order
+                let is_better = path.as_ref().is_none_or(|p| {
+                    (new_path.len(), in_range(&new_path)) < (p.len(), in_range(p))
+                });
+
+                if is_better {
+                    path = Some(new_path);
+                }
+            }
+        }
+
+        path.map(|path| *path.start())
+    }
+
+    /// Runs rounds until the battle ends, returning false if it was aborted.
+    fn fight(&mut self) -> bool {
+        while self.execute_round() {}
+
+        !self.aborted
+    }
+
+    /// Rounds finished times the hp left
+    fn outcome(&self) -> u64 {
+        self.total_turns as u64 * self.sum_total_hp()
+    }
+
+    fn execute_round(&mut self) -> bool {
+        self.execute_round_traced(&mut |_| ())
+    }
+
+    /// Gives every unit a turn, telling `on_event` what happens. Returns
+    /// false if a unit found no enemies left, ending the battle.
+    fn execute_round_traced(&mut self, on_event: &mut impl FnMut(Event)) -> bool {
+        // Units only move on their own turn, so until then each one is still
+        // where it started the round, unless it died
+        let mut turns: Vec<(u8, usize, UnitId)> = self
+            .tiles
+            .iter()
+            .enumerate()
+            .filter_map(|(i, tile)| {
+                let faction = tile.faction()?;
+
+                Some((self.rules.factions[faction].priority, i, tile.id()))
+            })
+            .collect();
+
+        turns.sort_unstable();
+
+        for (_, i, id) in turns {
+            if self.aborted {
+                return false;
+            }
+
+            let tile = self.tiles[i];
+            let Some(faction) = tile.faction().filter(|_| tile.id() == id) else {
+                continue;
+            };
+
+            // Action: Attack if enemy in range
+            if self.attack(i, on_event) {
+                continue;
+            }
+
+            // Action: Move
+            let enemies: Vec<usize> = (0..self.units.len())
+                .filter(|&other| other != faction)
+                .flat_map(|other| self.units[other].iter().copied())
+                .collect();
+
+            if enemies.is_empty() {
+                return false;
+            }
+
+            let next_move = match self.pathing {
+                Pathing::DistanceField => self.next_step(i, &enemies),
+                Pathing::Pairwise => self.next_step_pairwise(i, &enemies),
+            };
+            let mut i = i;
+
+            if let Some(next_move) = next_move {
+                let friends = &mut self.units[faction];
+
+                friends.remove(&i);
+                friends.insert(next_move);
+
+                assert_eq!(self.tiles[next_move], Tile::Open);
+
+                self.tiles[next_move] = replace(&mut self.tiles[i], Tile::Open);
+                on_event(Event::Moved {
+                    unit: id,
+                    from: self.tiles.pos_of(i),
+                    to: self.tiles.pos_of(next_move),
+                });
+
+                i = next_move;
+            }
+
+            // Action: Attack if enemy in range
+            self.attack(i, on_event);
+        }
+
+        if self.aborted {
+            return false;
+        }
+
+        self.total_turns += 1;
+        on_event(Event::RoundEnded {
+            round: self.total_turns,
+        });
+
+        true
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for row in self.tiles.rows() {
+            for tile in row {
+                let ch = match tile {
+                    Tile::Unit { faction, .. } => self.rules.factions[*faction].symbol,
+                    Tile::Open => '.',
+                    Tile::Wall => '#',
+                };
+
+                write!(f, "{}", ch)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[aoc_generator(day15)]
+pub fn input_generator(input: &str) -> Result<Map, ParseError> {
+    Map::parse(input, Rules::standard())
+}
+
+/// The weakest elves that win without losing anyone, found by `find_boost`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Boost {
+    pub attack: u8,
+    pub rounds: u8,
+    pub outcome: u64,
+}
+
+impl Display for Boost {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "attack {} wins in {} rounds, outcome {}",
+            self.attack, self.rounds, self.outcome
+        )
+    }
+}
+
+/// Fights the battle with the elves' attack set to `attack`, giving up as
+/// soon as an elf dies. Only a win with every elf alive counts.
+fn try_boost(map: &Map, attack: u8) -> Option<Boost> {
+    let mut map = map.clone();
+
+    map.rules.factions[ELVES].attack = attack;
+    map.abort_on_death = Some(ELVES);
+
+    let won = map.fight() && map.winner() == Some(ELVES);
+
+    won.then(|| Boost {
+        attack,
+        rounds: map.total_turns,
+        outcome: map.outcome(),
+    })
+}
+
+/// Tries each attack power, on its own thread when there's more than one.
+fn try_boosts(map: &Map, attacks: &[u8]) -> Vec<Option<Boost>> {
+    if attacks.len() == 1 {
+        return vec![try_boost(map, attacks[0])];
+    }
+
+    thread::scope(|scope| {
+        let battles: Vec<_> = attacks
+            .iter()
+            .map(|&attack| scope.spawn(move || try_boost(map, attack)))
+            .collect();
+
+        battles
+            .into_iter()
+            .map(|battle| battle.join().expect("battle thread panicked"))
+            .collect()
+    })
+}
+
+/// Finds the least elf attack power above the current one that wins without
+/// an elf dying, trying `threads` powers at once in increasing batches.
+/// Battles that lose an elf are given up on straight away, so most of the
+/// time goes on the winning one.
+///
+/// Every power is tried in order, as more power doesn't always help: a unit
+/// killed sooner changes who moves where. On the puzzle input 14 and 15 win,
+/// 16 to 18 lose an elf and 19 wins again, so a binary search can miss the
+/// least one.
+pub fn find_boost(map: &Map, threads: usize) -> Option<Boost> {
+    // No more power is needed than it takes to kill the toughest in one hit
+    let max = map.rules.factions.iter().map(|faction| faction.hp).max().unwrap_or(0);
+    let attacks: Vec<u8> = (map.rules.factions[ELVES].attack + 1..=max).collect();
+
+    attacks
+        .chunks(threads.max(1))
+        .find_map(|attacks| try_boosts(map, attacks).into_iter().flatten().next())
+}
+
+#[aoc(day15, part1, Chars)]
+pub fn part1_chars(map: &Map) -> u64 {
+    let mut map = map.clone();
+
+    map.fight();
+    map.outcome()
+}
+
+/// The old pathing, as a baseline for `aoc bench`
+#[aoc(day15, part1, Pairwise, baseline)]
+pub fn part1_pairwise(map: &Map) -> u64 {
+    let mut map = map.clone();
+
+    map.pathing = Pathing::Pairwise;
+    map.fight();
+    map.outcome()
+}
+
+#[aoc(day15, part2, Chars)]
+pub fn part2_chars(map: &Map) -> Result<u64, SolveError> {
+    let threads = thread::available_parallelism().map_or(1, usize::from);
+    let boost = find_boost(map, threads).ok_or("no attack power lets the elves win unharmed")?;
+
+    Ok(boost.outcome)
+}
+
+// https://www.reddit.com/r/adventofcode/comments/a70ohq/day_15_need_assistance_on_part_1/ec09zmg
+// #[test]
+// fn test_reddit1() {
+//     let input = "##########################
+// #######..#...###.........#
+// #######......#G##GE.....##
+// #######......#...E......##
+// #######....E.........E#.##
+// #####....................#
+// #############............#
+// ##########################";
+//     let mut map = input_generator(input).unwrap();
+
+//     while map.execute_round() {}
+
+//     println!("{}", map);
+
+//     let elf1 = map.tiles[map.width + 18]; // 35
+//     let elf2 = map.tiles[77];
+//     let elf3 = map.tiles[79];
+//     let elf4 = map.tiles[100];
+
+//     assert_eq!(map.total_turns, 40);
+
+//     map.print_all_npcs();
+
+//     assert!(elf1.is_elf(), "{:?}", elf1);
+//     assert_eq!(elf1.hp(), 200);
+
+//     assert!(elf2.is_elf(), "{:?}", elf2);
+//     assert_eq!(elf2.hp(), 200);
+
+//     assert!(elf3.is_elf(), "{:?}", elf3);
+//     assert_eq!(elf3.hp(), 83);
+
+//     assert!(elf4.is_elf(), "{:?}", elf4);
+//     assert_eq!(elf4.hp(), 128);
+
+// }
+
+#[test]
+fn test_reddit2() {
+    let input = "#######
+#.E..G#
+#.#####
+#G#####
+#######";
+    let mut map = input_generator(input).unwrap();
+
+    map.execute_round();
+
+    let elf1 = map.tiles[10];
+    let goblin1 = map.tiles[11];
+    let goblin2 = map.tiles[15];
+
+    assert!(elf1.is_elf(), "{:?}", elf1);
+    assert_eq!(elf1.hp(), 197);
+
+    assert!(goblin1.is_goblin(), "{:?}", goblin1);
+    assert_eq!(goblin1.hp(), 200);
+
+    assert!(goblin2.is_goblin(), "{:?}", goblin2);
+    assert_eq!(goblin2.hp(), 200);
+}
+
+#[test]
+fn test_reddit3() {
+    let input = "########
+#..E..G#
+#G######
+########";
+    let mut map = input_generator(input).unwrap();
+
+    map.execute_round();
+
+    let elf1 = map.tiles[10];
+    let goblin1 = map.tiles[9];
+    let goblin2 = map.tiles[13];
+
+    assert!(elf1.is_elf(), "{:?}", elf1);
+    assert_eq!(elf1.hp(), 197);
+
+    assert!(goblin1.is_goblin(), "{:?}", goblin1);
+    assert_eq!(goblin1.hp(), 200);
+
+    assert!(goblin2.is_goblin(), "{:?}", goblin2);
+    assert_eq!(goblin2.hp(), 200);
+}
+
+#[test]
+fn test_reddit4() {
+    let input = "####
+#GG#
+#.E#
+####";
+    let mut map = input_generator(input).unwrap();
+
+    map.execute_round();
+
+    let elf1 = map.tiles[10];
+    let goblin1 = map.tiles[6];
+    let goblin2 = map.tiles[9];
+
+    assert!(elf1.is_elf(), "{:?}", elf1);
+    assert_eq!(elf1.hp(), 194, "{:?}", elf1);
+
+    assert!(goblin1.is_goblin(), "{:?}", goblin1);
+    assert_eq!(goblin1.hp(), 197, "{:?}", goblin1);
+
+    assert!(goblin2.is_goblin(), "{:?}", goblin2);
+    assert_eq!(goblin2.hp(), 200, "{:?}", goblin2);
+}
+
+#[test]
+fn test_reddit5() {
+    let input = "#####
+#..E#
+#...#
+#G..#
+#####";
+    let mut map = input_generator(input).unwrap();
+
+    map.execute_round();
+
+    let elf1 = map.tiles[7];
+    let goblin1 = map.tiles[11];
+
+    assert!(elf1.is_elf(), "{:?}", elf1);
+    assert_eq!(elf1.hp(), 200, "{:?}", elf1);
+
+    assert!(goblin1.is_goblin(), "{:?}", goblin1);
+    assert_eq!(goblin1.hp(), 200, "{:?}", goblin1);
+}
+
+#[test]
+fn test_reddit6() {
+    let input = "#####
+##E##
+#EGE#
+##G##
+#####";
+    let mut map = input_generator(input).unwrap();
+
+    while map.execute_round() {}
+
+    let elf1 = map.tiles[7];
+    let elf2 = map.tiles[12];
+    let elf3 = map.tiles[13];
+
+    assert!(elf1.is_elf(), "{:?}", elf1);
+    assert_eq!(elf1.hp(), 134, "{:?}", elf1);
+
+    assert!(elf2.is_elf(), "{:?}", elf2);
+    assert_eq!(elf2.hp(), 2, "{:?}", elf2);
+
+    assert!(elf3.is_elf(), "{:?}", elf3);
+    assert_eq!(elf3.hp(), 200, "{:?}", elf3);
+}
+
+#[test]
+fn test_aoc() {
+    let input = "#######
+#.G...#
+#...EG#
+#.#.#G#
+#..G#E#
+#.....#
+#######";
+    let mut map = input_generator(input).unwrap();
+
+    let mut trace = Trace::new();
+
+    while map.execute_round_traced(&mut |event| trace.record(event)) {}
+
+    // Both elves fall, and every goblin survives
+    let died = trace.filter(|event| matches!(event, Event::Died { .. }));
+
+    assert!(matches!(died.events(), [Event::Died { unit: 1 | 5, .. }, Event::Died { unit: 1 | 5, .. }]));
+    assert_eq!(trace.filter(|event| matches!(event, Event::RoundEnded { .. })).events().len(), 47);
+
+    let goblin1 = map.tiles[8];
+    let goblin2 = map.tiles[16];
+    let goblin3 = map.tiles[26];
+    let goblin4 = map.tiles[40];
+
+    assert!(goblin1.is_goblin(), "{:?}", goblin1);
+    assert_eq!(goblin1.hp(), 200, "{:?}", goblin1);
+
+    assert!(goblin2.is_goblin(), "{:?}", goblin2);
+    assert_eq!(goblin2.hp(), 131, "{:?}", goblin2);
+
+    assert!(goblin3.is_goblin(), "{:?}", goblin3);
+    assert_eq!(goblin3.hp(), 59, "{:?}", goblin3);
+
+    assert!(goblin4.is_goblin(), "{:?}", goblin4);
+    assert_eq!(goblin4.hp(), 200, "{:?}", goblin4);
+
+    assert_eq!(map.total_turns, 47);
+}
+
+#[test]
+fn test_trace() {
+    let input = "####
+#GG#
+#.E#
+####";
+    let mut map = input_generator(input).unwrap();
+    let mut trace = Trace::new();
+
+    map.execute_round_traced(&mut |event| trace.record(event));
+
+    let reference = [
+        Event::Moved {
+            unit: 0,
+            from: (1, 1),
+            to: (1, 2),
+        },
+        Event::Attacked {
+            attacker: 0,
+            target: 2,
+            damage: 3,
+        },
+        Event::Attacked {
+            attacker: 1,
+            target: 2,
+            damage: 3,
+        },
+        Event::Attacked {
+            attacker: 2,
+            target: 1,
+            damage: 3,
+        },
+        Event::RoundEnded { round: 1 },
+    ];
+
+    assert_eq!(trace.diff(&reference), None);
+    assert_eq!(
+        trace.diff(&reference[..4]).map(|mismatch| mismatch.to_string()),
+        Some("event 4: expected nothing, got round 1 ended".to_owned())
+    );
+    assert_eq!(
+        trace.filter(|event| matches!(event, Event::Attacked { target: 2, .. })).to_string(),
+        "#0 hit #2 for 3\n#1 hit #2 for 3\n"
+    );
+}
+
+#[test]
+fn test_trace_deaths() {
+    let input = "#######
+#.G...#
+#...EG#
+#.#.#G#
+#..G#E#
+#.....#
+#######";
+    let mut map = input_generator(input).unwrap();
+    let mut trace = Trace::new();
+
+    while map.execute_round_traced(&mut |event| trace.record(event)) {}
+
+    let deaths = trace.filter(|event| matches!(event, Event::Died { .. }));
+
+    // Both elves die, the goblins all live
+    assert_eq!(deaths.events().len(), 2);
+    assert!(deaths.events().iter().all(|event| matches!(event, Event::Died { unit: 1 | 5, .. })));
+    assert_eq!(trace.events().last(), Some(&Event::RoundEnded { round: 47 }));
+}
+
+#[test]
+fn test_aoc_p2() {
+    let input = "#######
+#.G...#
+#...EG#
+#.#.#G#
+#..G#E#
+#.....#
+#######";
+    let mut map = input_generator(input).unwrap();
+
+    map.rules_mut().factions[ELVES].attack = 15;
+
+    let mut trace = Trace::new();
+
+    while map.execute_round_traced(&mut |event| trace.record(event)) {}
+
+    // Every goblin falls, and no elf does
+    let died: Vec<_> = trace
+        .events()
+        .iter()
+        .filter_map(|event| match event {
+            Event::Died { unit, .. } => Some(*unit),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(died.len(), 4);
+    assert!(died.iter().all(|unit| [0, 2, 3, 4].contains(unit)), "{:?}", died);
+
+    let elf1 = map.tiles[10];
+    let elf2 = map.tiles[18];
+
+    assert!(elf1.is_elf(), "{:?}", elf1);
+    assert_eq!(elf1.hp(), 158, "{:?}", elf1);
+
+    assert!(elf2.is_elf(), "{:?}", elf2);
+    assert_eq!(elf2.hp(), 14, "{:?}", elf2);
+
+    assert_eq!(map.total_turns, 29);
+    assert_eq!(map.sum_total_hp(), 172);
+}
+
+#[test]
+fn test_pathing_agrees() {
+    let inputs = [
+        "#######
+#E..G.#
+#...#.#
+#.G.#G#
+#######",
+        "#######
+#.G...#
+#...EG#
+#.#.#G#
+#..G#E#
+#.....#
+#######",
+        "#######
+#E.G#.#
+#.#G..#
+#G.#.G#
+#G..#.#
+#...E.#
+#######",
+        "#########
+#G......#
+#.E.#...#
+#..##..G#
+#...##..#
+#...#...#
+#.G...G.#
+#.....G.#
+#########",
+    ];
+
+    for input in inputs {
+        let traces = [Pathing::DistanceField, Pathing::Pairwise].map(|pathing| {
+            let mut map = input_generator(input).unwrap();
+            let mut trace = Trace::new();
+
+            map.pathing = pathing;
+
+            while map.execute_round_traced(&mut |event| trace.record(event)) {}
+
+            trace
+        });
+
+        assert_eq!(traces[0].diff(traces[1].events()), None, "{}", input);
+        assert!(traces[0].events().iter().any(|event| matches!(event, Event::Moved { .. })));
+    }
+}
+
+#[test]
+fn test_find_boost() {
+    let input = "#######
+#.G...#
+#...EG#
+#.#.#G#
+#..G#E#
+#.....#
+#######";
+    let map = input_generator(input).unwrap();
+    let boost = Boost {
+        attack: 15,
+        rounds: 29,
+        outcome: 4988,
+    };
+
+    assert_eq!(find_boost(&map, 1), Some(boost));
+    assert_eq!(find_boost(&map, 3), Some(boost));
+
+    let input = "#######
+#E..EG#
+#.#G.E#
+#E.##E#
+#G..#.#
+#..E#.#
+#######";
+    let map = input_generator(input).unwrap();
+
+    let boost = Boost {
+        attack: 4,
+        rounds: 33,
+        outcome: 31284,
+    };
+
+    assert_eq!(find_boost(&map, 2), Some(boost));
+
+    // Without any elves there's nothing to boost
+    let err = part2_chars(&input_generator("#####\n#G.G#\n#####").unwrap()).unwrap_err();
+
+    assert_eq!(err.to_string(), "no attack power lets the elves win unharmed");
+}
+
+#[test]
+fn test_find_boost_input() {
+    let input = crate::input::load(2018, 15).unwrap();
+    let map = input_generator(&input).unwrap();
+
+    // Winning isn't monotonic in attack power here
+    assert!(try_boost(&map, 15).is_some());
+    assert_eq!(try_boost(&map, 16), None);
+    assert!(try_boost(&map, 19).is_some());
+
+    let boost = find_boost(&map, 4).unwrap();
+
+    assert_eq!(
+        boost,
+        Boost {
+            attack: 14,
+            rounds: 48,
+            outcome: 58128
+        }
+    );
+    assert_eq!(boost.to_string(), "attack 14 wins in 48 rounds, outcome 58128");
+}
+
+#[test]
+fn test_abort_on_death() {
+    let input = "#######
+#.G...#
+#...EG#
+#.#.#G#
+#..G#E#
+#.....#
+#######";
+    let mut map = input_generator(input).unwrap();
+    let mut trace = Trace::new();
+
+    map.abort_on_death = Some(ELVES);
+
+    while map.execute_round_traced(&mut |event| trace.record(event)) {}
+
+    // Stopped right after the first elf died, rather than fighting on to 47 rounds
+    assert!(matches!(trace.events().last(), Some(Event::Died { unit: 1 | 5, .. })));
+    assert!(map.total_turns < 47);
+    assert_eq!(try_boost(&input_generator(input).unwrap(), 14), None);
+}
+
+#[test]
+fn test_factions() {
+    let faction = |symbol, hp, attack| FactionRules {
+        symbol,
+        hp,
+        attack,
+        priority: 1,
+    };
+    let rules = Rules {
+        factions: vec![faction('A', 10, 10), faction('B', 100, 1), faction('C', 5, 5)],
+    };
+    let mut map = Map::parse("#####\n#ABC#\n#####", rules.clone()).unwrap();
+
+    // B picks off the weaker C first, then is worn down by A
+    while map.execute_round() {}
+
+    assert_eq!(map.winner(), Some(0));
+    assert_eq!((map.total_turns, map.sum_total_hp()), (8, 8));
+    assert_eq!(map.to_string(), "#####\n#A..#\n#####\n");
+
+    // C goes first when it has the lowest priority
+    let mut rules = rules;
+
+    rules.factions[2].priority = 0;
+
+    let mut map = Map::parse("#####\n#ABC#\n#####", rules).unwrap();
+    let mut trace = Trace::new();
+
+    map.execute_round_traced(&mut |event| trace.record(event));
+
+    assert_eq!(
+        trace.events()[0],
+        Event::Attacked {
+            attacker: 2,
+            target: 1,
+            damage: 5
+        }
+    );
+    assert!(Map::parse("#####\n#ABD#\n#####", Rules::standard()).is_err());
+}
+
+// #[test]
+// fn test_aoc2() {
+//     let input = "#######
+// #G..#E#
+// #E#E.E#
+// #G.##.#
+// #...#E#
+// #...E.#
+// #######";
+//     let mut map = input_generator(input).unwrap();
+
+//     while map.execute_round() {}
+
+//     map.print_all_npcs();
+
+//     let elf1 = map.tiles[12];
+//     let elf2 = map.tiles[15];
+//     let elf3 = map.tiles[23];
+//     let elf4 = map.tiles[29];
+//     let elf5 = map.tiles[33];
+
+//     assert!(elf1.is_elf(), "{:?}", elf1);
+//     assert_eq!(elf1.hp(), 200, "{:?}", elf1);
+
+//     assert!(elf2.is_elf(), "{:?}", elf2);
+//     assert_eq!(elf2.hp(), 197, "{:?}", elf2);
+
+//     assert!(elf3.is_elf(), "{:?}", elf3);
+//     assert_eq!(elf3.hp(), 185, "{:?}", elf3);
+
+//     assert!(elf4.is_elf(), "{:?}", elf4);
+//     assert_eq!(elf4.hp(), 200, "{:?}", elf4);
+
+//     assert!(elf5.is_elf(), "{:?}", elf5);
+//     assert_eq!(elf5.hp(), 200, "{:?}", elf5);
+
+//     assert_eq!(map.total_turns, 37);
+// }
+
+#[test]
+fn test_reddit_p2() {
+    let input = "################################
+#...############################
+###G.###########################
+##.....#########################
+#......#########################
+##G...G.########################
+#G.....G########################
+###...G#########################
+###....#########################
+######.G.#######################
+#######....#####################
+###..#.....GG...G.E...##########
+##........G...#####...##.#######
+#.G..........#######...#..######
+#...####G...#########......#####
+#..G##.#..G.#########.......####
+#...##....E.#########...E.....##
+#...##......#########G......####
+#...........#########.......####
+#............#######...........#
+#.....E..G...E#####E...........#
+#.G...........G.............E###
+#...............E#####.#..######
+#..#..G...........####...#######
+#..#..............######.#######
+####.#...E.......###############
+########..##...#################
+##...##..###..##################
+#.......########################
+##...E..########################
+###......#######################
+################################";
+    let mut map = input_generator(input).unwrap();
+
+    map.rules_mut().factions[ELVES].attack = 25;
+
+    let elves: Vec<_> = map.tiles.iter().filter(|tile| tile.is_elf()).map(Tile::id).collect();
+    let mut trace = Trace::new();
+
+    while map.total_turns < 20 && map.execute_round_traced(&mut |event| trace.record(event)) {}
+
+    assert_eq!(map.total_turns, 20);
+
+    // 12 of the 20 goblins fall, and none of the elves
+    let died = trace.filter(|event| matches!(event, Event::Died { .. }));
+
+    assert_eq!(died.events().len(), 12);
+    assert!(died.events().iter().all(|event| match event {
+        Event::Died { unit, .. } => !elves.contains(unit),
+        _ => false,
+    }));
+
+    let elf1 = map.tiles[365];
+    let elf2 = map.tiles[522];
+    let elf3 = map.tiles[685];
+    let goblin = map.tiles[490];
+
+    assert!(elf1.is_elf(), "{:?}", elf1);
+    assert_eq!(elf1.hp(), 143, "{:?}", elf1);
+
+    assert!(elf2.is_elf(), "{:?}", elf2);
+    assert_eq!(elf2.hp(), 74, "{:?}", elf2);
+
+    assert!(elf3.is_elf(), "{:?}", elf3);
+    assert_eq!(elf3.hp(), 200, "{:?}", elf3);
+
+    assert!(goblin.is_goblin(), "{:?}", goblin);
+    assert_eq!(goblin.hp(), 25, "{:?}", goblin);
+}
+
+#[test]
+fn test_damage() {
+    let input = "\
+####
+##E#
+#GG#
+####";
+    let mut map = input_generator(input).unwrap();
+
+    let mut trace = Trace::new();
+
+    while map.execute_round_traced(&mut |event| trace.record(event)) {}
+
+    // The goblin diagonal to the elf can't reach it, so the elf and the goblin
+    // below it trade blows. The elf strikes first and kills it in round 67 on
+    // 2 hp, then the other goblin steps into the gap and finishes the elf.
+    assert_eq!(map.total_turns, 67);
+    assert_eq!(map.outcome(), 13400);
+    assert_eq!(
+        trace.filter(|event| matches!(event, Event::Moved { .. } | Event::Died { .. })).events(),
+        [
+            Event::Died { unit: 2, at: (2, 2) },
+            Event::Moved { unit: 1, from: (1, 2), to: (2, 2) },
+            Event::Died { unit: 0, at: (2, 1) },
+        ]
+    );
+    assert_eq!(trace.filter(|event| matches!(event, Event::Attacked { attacker: 1, .. })).events().len(), 1);
+
+    let goblin = map.tiles[10];
+
+    assert!(goblin.is_goblin(), "{:?}", goblin);
+    assert_eq!(goblin.hp(), 200, "{:?}", goblin);
+    assert!(!map.tiles[6].is_elf(), "{:?}", map.tiles[6]);
+}
@@ -0,0 +1,3 @@
+pub mod day11;
+pub mod day14;
+pub mod day15;
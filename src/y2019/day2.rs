@@ -0,0 +1,58 @@
+use aoc_derive::{aoc, aoc_generator};
+
+use crate::intcode::{IntcodeError, Vm};
+use crate::parse::ParseError;
+use crate::registry::SolveError;
+
+type Program = Vec<i64>;
+
+#[aoc_generator(day2)]
+pub fn input_generator(input: &str) -> Result<Program, ParseError> {
+    Vm::parse(input)
+}
+
+/// Runs the program with a noun and verb in addresses 1 and 2, giving what
+/// ends up in address 0.
+fn run(program: &[i64], noun: i64, verb: i64) -> Result<i64, IntcodeError> {
+    let mut vm = Vm::new(program.to_vec());
+
+    vm.write(1, noun);
+    vm.write(2, verb);
+    vm.run(&[])?;
+
+    Ok(vm.read(0))
+}
+
+#[aoc(day2, part1)]
+pub fn part1(program: &Program) -> Result<i64, IntcodeError> {
+    run(program, 12, 2)
+}
+
+#[aoc(day2, part2)]
+pub fn part2(program: &Program) -> Result<i64, SolveError> {
+    for noun in 0..=99 {
+        for verb in 0..=99 {
+            // Some inputs crash the program, those aren't the answer
+            if run(program, noun, verb) == Ok(19690720) {
+                return Ok(100 * noun + verb);
+            }
+        }
+    }
+
+    Err("no noun and verb give 19690720".into())
+}
+
+#[test]
+fn test_day2_errors() {
+    let program = Vm::parse("1,0,0,0,98").unwrap();
+
+    assert_eq!(part1(&program), Err(IntcodeError::BadInstruction { addr: 4, value: 98 }));
+
+    let program = Vm::parse("1,0,0,0,99").unwrap();
+
+    assert_eq!(part1(&program), Ok(2));
+    assert_eq!(
+        part2(&program).unwrap_err().to_string(),
+        "no noun and verb give 19690720"
+    );
+}
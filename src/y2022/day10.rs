@@ -0,0 +1,180 @@
+use std::collections::HashSet;
+
+use aoc_derive::{FromLine, aoc};
+
+use crate::answer::Answer;
+use crate::ocr;
+use crate::parse::{ParseError, Source};
+use crate::registry::SolveError;
+
+#[derive(Debug, FromLine)]
+pub enum Instruction {
+    #[pattern("noop")]
+    Noop,
+    #[pattern("addx {0}")]
+    Addx(i32),
+}
+
+fn instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    Source::new(input).lines(input)
+}
+
+impl Instruction {
+    /// How many cycles the instruction takes to finish
+    fn cycles(&self) -> usize {
+        match self {
+            Instruction::Noop => 1,
+            Instruction::Addx(_) => 2,
+        }
+    }
+}
+
+/// Why `Cpu::run` returned
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stop {
+    /// The program ran out of instructions
+    Halted,
+    /// The next cycle is a breakpoint
+    Breakpoint,
+}
+
+/// The handheld's CPU, with its one register
+pub struct Cpu<'a> {
+    program: &'a [Instruction],
+    /// The instruction being executed
+    pc: usize,
+    /// Cycles spent on it so far
+    progress: usize,
+    /// Cycles finished so far
+    cycle: usize,
+    x: i32,
+    breakpoints: HashSet<usize>,
+}
+
+impl<'a> Cpu<'a> {
+    pub fn new(program: &'a [Instruction]) -> Self {
+        Cpu {
+            program,
+            pc: 0,
+            progress: 0,
+            cycle: 0,
+            x: 1,
+            breakpoints: HashSet::new(),
+        }
+    }
+
+    /// The number of cycles finished
+    pub fn cycle(&self) -> usize {
+        self.cycle
+    }
+
+    pub fn x(&self) -> i32 {
+        self.x
+    }
+
+    /// Makes `run` stop before starting `cycle`, counting from 1.
+    pub fn add_breakpoint(&mut self, cycle: usize) {
+        self.breakpoints.insert(cycle);
+    }
+
+    /// Runs a single cycle, calling `observer` with the cycle's number and
+    /// the value of X during it. Instructions only take effect once their
+    /// last cycle is over. Returns false without doing anything once the
+    /// program has finished.
+    pub fn step(&mut self, mut observer: impl FnMut(usize, i32)) -> bool {
+        let Some(instr) = self.program.get(self.pc) else {
+            return false;
+        };
+
+        self.cycle += 1;
+        observer(self.cycle, self.x);
+        self.progress += 1;
+
+        if self.progress == instr.cycles() {
+            if let Instruction::Addx(val) = instr {
+                self.x += val;
+            }
+
+            self.pc += 1;
+            self.progress = 0;
+        }
+
+        true
+    }
+
+    /// Steps until the program finishes or the next cycle is a breakpoint.
+    /// At least one cycle is run, so calling it again carries on past the
+    /// breakpoint.
+    pub fn run(&mut self, mut observer: impl FnMut(usize, i32)) -> Stop {
+        loop {
+            if !self.step(&mut observer) {
+                return Stop::Halted;
+            }
+
+            if self.breakpoints.contains(&(self.cycle + 1)) {
+                return Stop::Breakpoint;
+            }
+        }
+    }
+}
+
+#[aoc(day10, part1, Chars)]
+pub fn part1_chars(input: &str) -> Result<i32, ParseError> {
+    let instructions = instructions(input)?;
+    let mut signal_strength = 0;
+
+    Cpu::new(&instructions).run(|cycle, x| {
+        if cycle % 40 == 20 {
+            signal_strength += cycle as i32 * x;
+        }
+    });
+
+    Ok(signal_strength)
+}
+
+#[aoc(day10, part2, Chars)]
+pub fn part2_chars(input: &str) -> Result<Answer, SolveError> {
+    let instructions = instructions(input)?;
+    let mut rows = vec![vec![false; 40]; 6];
+
+    // The CRT draws one pixel per cycle, lit if the 3 pixel wide sprite
+    // centred on X covers it
+    Cpu::new(&instructions).run(|cycle, x| {
+        let (row, col) = ((cycle - 1) / 40, (cycle - 1) % 40);
+
+        if let Some(row) = rows.get_mut(row) {
+            row[col] = (x - col as i32).abs() <= 1;
+        }
+    });
+
+    Ok(Answer::String(ocr::recognize(&rows)?))
+}
+
+#[test]
+fn test_day10_cpu() {
+    let program = instructions("noop\naddx 3\naddx -5").unwrap();
+    let mut cpu = Cpu::new(&program);
+    let mut during = Vec::new();
+
+    cpu.add_breakpoint(4);
+
+    assert_eq!(cpu.run(|cycle, x| during.push((cycle, x))), Stop::Breakpoint);
+    assert_eq!((cpu.cycle(), cpu.x()), (3, 4));
+    assert!(cpu.step(|cycle, x| during.push((cycle, x))));
+    assert_eq!((cpu.cycle(), cpu.x()), (4, 4));
+    assert_eq!(cpu.run(|cycle, x| during.push((cycle, x))), Stop::Halted);
+    assert_eq!((cpu.cycle(), cpu.x()), (5, -1));
+    assert!(!cpu.step(|_, _| panic!("the program has finished")));
+    assert_eq!(during, [(1, 1), (2, 1), (3, 1), (4, 4), (5, 4)]);
+}
+
+#[test]
+fn test_day10_unknown_glyph() {
+    // X stays at 1, so the first three columns are lit all the way down
+    let err = part2_chars(&"noop\n".repeat(240)).unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "unknown glyph 0 at column 0:\n###\n###\n###\n###\n###\n###"
+    );
+}
@@ -0,0 +1,310 @@
+use std::fmt::{self, Display, Formatter};
+
+use aoc_derive::{FromLine, aoc};
+
+use crate::parse::{FromLine, ParseError, Source};
+use crate::registry::SolveError;
+
+#[derive(FromLine)]
+enum Line {
+    #[pattern("$ cd {0}")]
+    Cd(String),
+    #[pattern("$ ls")]
+    Ls,
+    #[pattern("dir {0}")]
+    Dir(String),
+    #[pattern("{size} {name}")]
+    File { size: u32, name: String },
+}
+
+/// An index into `FileSystem::nodes`
+pub type NodeId = usize;
+
+#[derive(Debug)]
+pub enum Kind {
+    /// Children in the order they were first listed
+    Dir(Vec<NodeId>),
+    File,
+}
+
+#[derive(Debug)]
+pub struct Node {
+    pub name: String,
+    /// None for the root
+    pub parent: Option<NodeId>,
+    pub kind: Kind,
+    /// For directories, the total size of everything inside
+    pub size: u32,
+}
+
+impl Node {
+    pub fn is_dir(&self) -> bool {
+        matches!(self.kind, Kind::Dir(_))
+    }
+}
+
+/// A directory tree rebuilt from a terminal transcript. Nodes live in one
+/// `Vec` and point at each other by index, and a child is always added after
+/// its parent.
+#[derive(Debug)]
+pub struct FileSystem {
+    nodes: Vec<Node>,
+}
+
+impl FileSystem {
+    pub const ROOT: NodeId = 0;
+
+    pub fn new() -> Self {
+        FileSystem {
+            nodes: vec![Node {
+                name: "/".into(),
+                parent: None,
+                kind: Kind::Dir(Vec::new()),
+                size: 0,
+            }],
+        }
+    }
+
+    /// Replays the `$ cd` and `$ ls` commands of a transcript. Going into or
+    /// listing a directory more than once is harmless, and so is `cd ..` at
+    /// the root.
+    pub fn replay(input: &str) -> Result<Self, ParseError> {
+        let source = Source::new(input);
+        let mut fs = FileSystem::new();
+        let mut cwd = Self::ROOT;
+
+        for raw_line in input.lines() {
+            cwd = match Line::from_line(&source, raw_line)? {
+                Line::Cd(dir) if dir == "/" => Self::ROOT,
+                Line::Cd(dir) if dir == ".." => fs.nodes[cwd].parent.unwrap_or(Self::ROOT),
+                Line::Cd(dir) => {
+                    let dir = fs.add(cwd, dir, Kind::Dir(Vec::new()));
+
+                    if !fs.nodes[dir].is_dir() {
+                        return Err(source.error(raw_line, "not a directory"));
+                    }
+
+                    dir
+                }
+                Line::Ls => cwd,
+                Line::Dir(name) => {
+                    fs.add(cwd, name, Kind::Dir(Vec::new()));
+                    cwd
+                }
+                Line::File { size, name } => {
+                    let file = fs.add(cwd, name, Kind::File);
+
+                    fs.nodes[file].size = size;
+                    cwd
+                }
+            };
+        }
+
+        // Children come after their parents, so going backwards every size
+        // is final by the time it's added to its parent's
+        for id in (1..fs.nodes.len()).rev() {
+            if let Some(parent) = fs.nodes[id].parent {
+                fs.nodes[parent].size += fs.nodes[id].size;
+            }
+        }
+
+        Ok(fs)
+    }
+
+    /// The child of `dir` called `name`, added if it isn't there yet.
+    fn add(&mut self, dir: NodeId, name: String, kind: Kind) -> NodeId {
+        let Kind::Dir(children) = &self.nodes[dir].kind else {
+            unreachable!("only directories are ever the working directory");
+        };
+
+        if let Some(&child) = children.iter().find(|&&child| self.nodes[child].name == name) {
+            return child;
+        }
+
+        let child = self.nodes.len();
+
+        self.nodes.push(Node {
+            name,
+            parent: Some(dir),
+            kind,
+            size: 0,
+        });
+
+        if let Kind::Dir(children) = &mut self.nodes[dir].kind {
+            children.push(child);
+        }
+
+        child
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id]
+    }
+
+    /// Every node matching a predicate, parents before their children
+    pub fn find<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&Node) -> bool + 'a,
+    ) -> impl Iterator<Item = NodeId> + 'a {
+        (0..self.nodes.len()).filter(move |&id| predicate(&self.nodes[id]))
+    }
+
+    /// The full path of a node, ie `/a/e`
+    pub fn path(&self, id: NodeId) -> String {
+        match self.nodes[id].parent {
+            None => "/".into(),
+            Some(Self::ROOT) => format!("/{}", self.nodes[id].name),
+            Some(parent) => format!("{}/{}", self.path(parent), self.nodes[id].name),
+        }
+    }
+
+    /// The path and total size of every directory, like `du`
+    pub fn du(&self) -> Vec<(String, u32)> {
+        self.find(Node::is_dir)
+            .map(|id| (self.path(id), self.nodes[id].size))
+            .collect()
+    }
+
+    fn write_tree(&self, f: &mut Formatter, id: NodeId, depth: usize) -> fmt::Result {
+        let node = &self.nodes[id];
+
+        write!(f, "{:indent$}- {} ", "", node.name, indent = depth * 2)?;
+
+        let Kind::Dir(children) = &node.kind else {
+            return writeln!(f, "(file, size={})", node.size);
+        };
+
+        writeln!(f, "(dir, size={})", node.size)?;
+
+        let mut children = children.clone();
+
+        children.sort_by(|&a, &b| self.nodes[a].name.cmp(&self.nodes[b].name));
+
+        for child in children {
+            self.write_tree(f, child, depth + 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        FileSystem::new()
+    }
+}
+
+/// Draws the tree the way the puzzle does, children sorted by name
+impl Display for FileSystem {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.write_tree(f, Self::ROOT, 0)
+    }
+}
+
+#[aoc(day7, part1, Chars)]
+pub fn part1_chars(input: &str) -> Result<u32, ParseError> {
+    let fs = FileSystem::replay(input)?;
+
+    Ok(fs
+        .du()
+        .into_iter()
+        .map(|(_, size)| size)
+        .filter(|&size| size <= 100_000)
+        .sum())
+}
+
+#[aoc(day7, part2, Chars)]
+pub fn part2_chars(input: &str) -> Result<u32, SolveError> {
+    let fs = FileSystem::replay(input)?;
+    let used = fs.node(FileSystem::ROOT).size;
+    let free_space = 70_000_000u32
+        .checked_sub(used)
+        .ok_or_else(|| format!("{} is used, more than the 70000000 on the disk", used))?;
+
+    fs.find(|node| node.is_dir() && node.size + free_space >= 30_000_000)
+        .map(|id| fs.node(id).size)
+        .min()
+        .ok_or_else(|| "no directory frees up enough space".into())
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = "$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k";
+
+#[test]
+fn test_day7_replay() {
+    let fs = FileSystem::replay(EXAMPLE).unwrap();
+
+    assert_eq!(
+        fs.du(),
+        [
+            ("/".to_owned(), 48381165),
+            ("/a".to_owned(), 94853),
+            ("/d".to_owned(), 24933642),
+            ("/a/e".to_owned(), 584)
+        ]
+    );
+    assert_eq!(
+        fs.to_string(),
+        "- / (dir, size=48381165)
+  - a (dir, size=94853)
+    - e (dir, size=584)
+      - i (file, size=584)
+    - f (file, size=29116)
+    - g (file, size=2557)
+    - h.lst (file, size=62596)
+  - b.txt (file, size=14848514)
+  - c.dat (file, size=8504156)
+  - d (dir, size=24933642)
+    - d.ext (file, size=5626152)
+    - d.log (file, size=8033020)
+    - j (file, size=4060174)
+    - k (file, size=7214296)
+"
+    );
+}
+
+#[test]
+fn test_day7_replay_idempotent() {
+    // Going back to the root and listing everything again changes nothing
+    let again = format!(
+        "{}\n$ cd /\n$ cd ..\n$ ls\ndir a\n14848514 b.txt\n$ cd a\n$ cd e\n$ ls\n584 i",
+        EXAMPLE
+    );
+    let fs = FileSystem::replay(&again).unwrap();
+
+    assert_eq!(fs.to_string(), FileSystem::replay(EXAMPLE).unwrap().to_string());
+    assert_eq!(
+        fs.find(|node| node.name == "i").map(|id| fs.path(id)).collect::<Vec<_>>(),
+        ["/a/e/i"]
+    );
+    assert!(FileSystem::replay(&format!("{}\n$ cd k", EXAMPLE)).is_err());
+}
+
+#[test]
+fn test_day7_over_capacity() {
+    let err = part2_chars("$ cd /\n$ ls\n70000001 big.bin").unwrap_err();
+
+    assert_eq!(err.to_string(), "70000001 is used, more than the 70000000 on the disk");
+}
@@ -0,0 +1,60 @@
+use aoc_derive::{aoc, aoc_generator};
+
+use crate::grid::{Grid, Pos};
+use crate::iter::IterExt;
+use crate::parse::ParseError;
+
+/// Left, up, right and down
+const DIRECTIONS: [(isize, isize); 4] = [(-1, 0), (0, -1), (1, 0), (0, 1)];
+
+#[aoc_generator(day8)]
+pub fn input_generator(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, |ch| ch.to_digit(10).map(|height| height as u8))
+}
+
+fn is_visible(map: &Grid<u8>, pos: Pos) -> bool {
+    let val = map[pos];
+
+    // Trees on the edge have nothing in the way
+    DIRECTIONS
+        .into_iter()
+        .any(|dir| map.ray(pos, dir).all(|pos| map[pos] < val))
+}
+
+#[aoc(day8, part1)]
+pub fn part1(map: &Grid<u8>) -> u64 {
+    let mut visible = 0;
+
+    for pos in map.positions() {
+        if is_visible(map, pos) {
+            visible += 1;
+        }
+    }
+
+    visible
+}
+
+fn score(map: &Grid<u8>, pos: Pos) -> usize {
+    let val = map[pos];
+
+    // Trees on the edge see nothing in at least one direction, so score 0
+    DIRECTIONS
+        .into_iter()
+        .map(|dir| map.ray(pos, dir).take_while_inclusive(|&pos| map[pos] < val).count())
+        .product()
+}
+
+#[aoc(day8, part2)]
+pub fn part2(map: &Grid<u8>) -> usize {
+    let mut high_score = 0;
+
+    for pos in map.positions() {
+        let score = score(map, pos);
+
+        if score > high_score {
+            high_score = score;
+        }
+    }
+
+    high_score
+}
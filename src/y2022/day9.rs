@@ -0,0 +1,69 @@
+use std::collections::HashSet;
+use std::iter;
+
+use aoc_derive::{FromLine, aoc};
+
+use crate::geometry::{Dir4, Point};
+use crate::parse::{FromLine, ParseError, Source};
+
+#[derive(FromLine)]
+#[pattern("{dir} {steps}")]
+struct Move {
+    dir: char,
+    steps: usize,
+}
+
+fn dirs(input: &str) -> Result<impl Iterator<Item = Dir4>, ParseError> {
+    let source = Source::new(input);
+    let moves = input
+        .lines()
+        .map(|line| {
+            let Move { dir, steps } = Move::from_line(&source, line)?;
+            let dir = Dir4::from_char(dir)
+                .ok_or_else(|| source.error(&line[..dir.len_utf8()], "expected `U`, `D`, `L` or `R`"))?;
+
+            Ok((dir, steps))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(moves
+        .into_iter()
+        .flat_map(|(dir, steps)| iter::repeat_n(dir, steps)))
+}
+
+/// Drags a rope of `num_knots` knots around, counting the squares its tail visits.
+fn tail_visits(input: &str, num_knots: usize) -> Result<usize, ParseError> {
+    let mut visited = HashSet::new();
+    let mut knots = vec![Point::default(); num_knots];
+
+    visited.insert(Point::default());
+
+    for dir in dirs(input)? {
+        knots[0] += dir.delta();
+
+        for i in 1..num_knots {
+            let (front, back) = (knots[i - 1], knots[i]);
+
+            // Still touching, so the rest of the rope stays put
+            if back.chebyshev(front) < 2 {
+                break;
+            }
+
+            knots[i] = back.step_towards(front);
+        }
+
+        visited.insert(knots[num_knots - 1]);
+    }
+
+    Ok(visited.len())
+}
+
+#[aoc(day9, part1)]
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    tail_visits(input, 2)
+}
+
+#[aoc(day9, part2)]
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    tail_visits(input, 10)
+}
@@ -0,0 +1,4 @@
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
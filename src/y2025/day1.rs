@@ -1,18 +1,32 @@
-use aoc_runner_derive::aoc;
+use aoc_derive::aoc;
+
+use crate::parse::{ParseError, Source};
+
+fn rotation<'a>(source: &Source<'a>, line: &'a str) -> Result<(&'a str, i16), ParseError> {
+    let (dir, num) = line
+        .split_at_checked(1)
+        .ok_or_else(|| source.error(line, "expected a rotation"))?;
+
+    if dir != "L" && dir != "R" {
+        return Err(source.error(dir, "expected `L` or `R`"));
+    }
+
+    Ok((dir, source.parse(num.trim())?))
+}
 
 #[aoc(day1, part1)]
-pub fn part1(input: &str) -> i16 {
+pub fn part1(input: &str) -> Result<i16, ParseError> {
+    let source = Source::new(input);
     let mut dial = 50;
     let mut password = 0;
 
     for line in input.lines() {
-        let (dir, num) = line.split_at(1);
-        let step = num.trim().parse::<i16>().unwrap();
+        let (dir, step) = rotation(&source, line)?;
 
         match dir {
             "L" => dial -= step,
             "R" => dial += step,
-            _ => continue,
+            _ => unreachable!(),
         }
 
         dial %= 100;
@@ -26,17 +40,17 @@ pub fn part1(input: &str) -> i16 {
         }
     }
 
-    password
+    Ok(password)
 }
 
 #[aoc(day1, part2)]
-pub fn part2(input: &str) -> i16 {
+pub fn part2(input: &str) -> Result<i16, ParseError> {
+    let source = Source::new(input);
     let mut dial: i16 = 50;
     let mut password = 0;
 
     for line in input.lines() {
-        let (dir, num) = line.split_at(1);
-        let step = num.trim().parse::<i16>().unwrap();
+        let (dir, step) = rotation(&source, line)?;
         let dial_before = dial;
 
         match dir {
@@ -63,22 +77,5 @@ pub fn part2(input: &str) -> i16 {
         }
     }
 
-    password
-}
-
-#[test]
-fn test_day1_part2() {
-    let input = "L68
-L30
-R48
-L5
-R60
-L55
-L1
-L99
-R14
-L82
-";
-
-    assert_eq!(part2(input), 6);
+    Ok(password)
 }
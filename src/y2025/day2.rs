@@ -1,11 +1,21 @@
-use aoc_runner_derive::aoc;
+use aoc_derive::{FromLine, aoc};
 
-fn generator(input: &str) -> impl Iterator<Item = (&str, &str)> + '_ {
-    input.split(',').map(|l| {
-        let mut iter = l.split('-');
+use crate::parse::{FromLine, ParseError, Source};
 
-        (iter.next().unwrap(), iter.next().unwrap())
-    })
+#[derive(FromLine)]
+#[pattern("{start}-{end}")]
+struct IdRange {
+    start: u64,
+    end: u64,
+}
+
+fn generator(input: &str) -> Result<Vec<IdRange>, ParseError> {
+    let source = Source::new(input);
+
+    input
+        .split(',')
+        .map(|range| IdRange::from_line(&source, range))
+        .collect()
 }
 
 fn is_valid(id: &str) -> bool {
@@ -20,13 +30,10 @@ fn is_valid(id: &str) -> bool {
 }
 
 #[aoc(day2, part1)]
-pub fn part1(input: &str) -> u64 {
+pub fn part1(input: &str) -> Result<u64, ParseError> {
     let mut sum = 0;
 
-    for (id1, id2) in generator(input) {
-        let start = id1.parse::<u64>().unwrap();
-        let end = id2.parse::<u64>().unwrap();
-
+    for IdRange { start, end } in generator(input)? {
         for num in start..=end {
             let id = num.to_string();
 
@@ -36,7 +43,7 @@ pub fn part1(input: &str) -> u64 {
         }
     }
 
-    sum
+    Ok(sum)
 }
 
 // Repeated patterns are not allowed:
@@ -108,14 +115,10 @@ pub fn is_valid_part2(id: &str) -> bool {
 }
 
 #[aoc(day2, part2)]
-pub fn part2(input: &str) -> u64 {
-    // let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+pub fn part2(input: &str) -> Result<u64, ParseError> {
     let mut sum = 0;
 
-    for (id1, id2) in generator(input) {
-        let start = id1.parse::<u64>().unwrap();
-        let end = id2.parse::<u64>().unwrap();
-
+    for IdRange { start, end } in generator(input)? {
         for num in start..=end {
             let id = num.to_string();
 
@@ -125,7 +128,7 @@ pub fn part2(input: &str) -> u64 {
         }
     }
 
-    sum
+    Ok(sum)
 }
 
 #[test]
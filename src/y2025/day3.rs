@@ -0,0 +1,113 @@
+use aoc_derive::aoc;
+
+use crate::iter::IterExt;
+use crate::parse::{ParseError, Source};
+
+/// The joltage digits of a bank, which has to have at least `len` batteries.
+fn bank<'a>(source: &Source<'a>, line: &'a str, len: usize) -> Result<Vec<u32>, ParseError> {
+    if line.len() < len {
+        return Err(source.error(line, format!("expected at least {} batteries", len)));
+    }
+
+    line.char_indices()
+        .map(|(i, _)| source.digit(line, i))
+        .collect()
+}
+
+#[aoc(day3, part1)]
+pub fn part1(input: &str) -> Result<u32, ParseError> {
+    let source = Source::new(input);
+    let mut joltage = 0;
+
+    for line in input.lines() {
+        let bank = bank(&source, line, 2)?;
+
+        // The first of the greatest digits, leaving room for a second one after it
+        let greatest_pos = bank[..bank.len() - 1]
+            .iter()
+            .argmax_by(|a, b| a.cmp(b))
+            .unwrap_or(0);
+        let greatest = bank[greatest_pos];
+        let next_greatest = bank[greatest_pos + 1..].iter().copied().max().unwrap_or(0);
+
+        joltage += greatest * 10 + next_greatest;
+    }
+
+    Ok(joltage)
+}
+
+fn prune(batteries: &mut Vec<u32>) -> u64 {
+    loop {
+        let len = batteries.len();
+
+        if len <= 12 {
+            break;
+        }
+
+        let mut iter = batteries.iter().enumerate().peekable();
+
+        while let Some((pos, &digit)) = iter.next() {
+            let Some(&(_, &next_digit)) = iter.peek() else {
+                batteries.pop();
+                break;
+            };
+
+            if digit < next_digit {
+                batteries.remove(pos);
+                break;
+            }
+        }
+    }
+
+    batteries
+        .iter()
+        .fold(0, |joltage, &digit| joltage * 10 + digit as u64)
+}
+
+#[aoc(day3, part2)]
+pub fn part2(input: &str) -> Result<u64, ParseError> {
+    let source = Source::new(input);
+    let mut joltage = 0u64;
+
+    for line in input.lines() {
+        let mut batteries = bank(&source, line, 12)?;
+
+        joltage += prune(&mut batteries);
+    }
+
+    Ok(joltage)
+}
+
+#[test]
+fn test_day3_prune() {
+    let prune = |line| prune(&mut bank(&Source::new(line), line, 12).unwrap());
+
+    assert_eq!(prune("987654321111111"), 987654321111);
+    assert_eq!(prune("811111111111119"), 811111111119);
+    assert_eq!(prune("818181911112111"), 888911112111);
+    assert_eq!(prune("234234234234278"), 434234234278);
+}
+
+#[test]
+fn test_day3_malformed() {
+    let err = part1("12\n3x4").unwrap_err();
+
+    assert_eq!(
+        (err.line, err.column, err.message.as_str()),
+        (2, 2, "expected a digit")
+    );
+
+    let err = part1("12\n\n34").unwrap_err();
+
+    assert_eq!(
+        (err.line, err.message.as_str()),
+        (2, "expected at least 2 batteries")
+    );
+
+    let err = part2("987654321111111\n98765").unwrap_err();
+
+    assert_eq!(
+        (err.line, err.message.as_str()),
+        (2, "expected at least 12 batteries")
+    );
+}
@@ -0,0 +1,42 @@
+use aoc_derive::{aoc, aoc_generator};
+
+use crate::automaton::Automaton;
+use crate::grid::{Grid, Pos};
+use crate::parse::ParseError;
+
+/// Where the rolls of paper are
+type Input = Grid<bool>;
+
+#[aoc_generator(day4)]
+pub fn input_generator(input: &str) -> Result<Input, ParseError> {
+    Grid::parse(input, |ch| match ch {
+        '@' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+fn rolls_to_remove(map: &Input) -> Vec<Pos> {
+    map.positions()
+        .filter(|&pos| map[pos])
+        .filter(|&pos| map.neighbours8(pos).filter(|&pos| map[pos]).count() < 4)
+        .collect()
+}
+
+#[aoc(day4, part1)]
+pub fn part1(map: &Input) -> usize {
+    rolls_to_remove(map).len()
+}
+
+#[aoc(day4, part2)]
+pub fn part2(map: &Input) -> usize {
+    // Rolls with fewer than 4 neighbours get removed, until none are left to remove
+    let mut automaton = Automaton::new(map.clone(), |&roll, neighbours| {
+        roll && neighbours.count(|&roll| roll) >= 4
+    });
+    let mut total = 0;
+
+    automaton.run(|_, _, removed| total += removed.len());
+
+    total
+}
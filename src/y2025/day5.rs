@@ -0,0 +1,41 @@
+use aoc_derive::{FromLine, aoc};
+
+use crate::parse::{ParseError, Source};
+use crate::range_set::RangeSet;
+
+/// A range of fresh ingredient IDs
+#[derive(FromLine)]
+#[pattern("{start}-{end}")]
+struct Fresh {
+    start: u64,
+    end: u64,
+}
+
+fn parse_ranges<'a>(source: &Source<'a>, section: &'a str) -> Result<RangeSet<u64>, ParseError> {
+    Ok(source
+        .lines::<Fresh>(section)?
+        .into_iter()
+        .map(|fresh| fresh.start..=fresh.end)
+        .collect())
+}
+
+#[aoc(day5, part1)]
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let source = Source::new(input);
+    let (ranges, ids) = source.split_once(input, "\n\n")?;
+    let fresh = parse_ranges(&source, ranges)?;
+    let ids = ids
+        .lines()
+        .map(|id| source.parse::<u64>(id))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ids.into_iter().filter(|&id| fresh.contains(id)).count())
+}
+
+#[aoc(day5, part2)]
+pub fn part2(input: &str) -> Result<u128, ParseError> {
+    let source = Source::new(input);
+    let section = input.split("\n\n").next().unwrap_or_default();
+
+    Ok(parse_ranges(&source, section)?.len())
+}
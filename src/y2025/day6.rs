@@ -0,0 +1,164 @@
+use aoc_derive::aoc;
+
+use crate::parse::{ParseError, Source};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Op {
+    Add,
+    Mul,
+}
+
+impl Op {
+    fn apply(self, numbers: impl Iterator<Item = usize>) -> usize {
+        match self {
+            Op::Add => numbers.sum(),
+            Op::Mul => numbers.product(),
+        }
+    }
+}
+
+/// The rows of numbers, and each problem's operator along with the byte
+/// range of its column.
+struct Worksheet<'a> {
+    rows: Vec<&'a str>,
+    problems: Vec<(Op, usize, usize)>,
+}
+
+impl<'a> Worksheet<'a> {
+    fn parse(source: &Source<'a>, input: &'a str) -> Result<Self, ParseError> {
+        let mut rows = input.lines().collect::<Vec<_>>();
+        let ops = rows.pop().filter(|_| !rows.is_empty()).ok_or_else(|| {
+            source.error(input, "expected rows of numbers and a row of operators")
+        })?;
+        let mut starts = Vec::new();
+
+        for (i, ch) in ops.char_indices() {
+            match ch {
+                '+' => starts.push((Op::Add, i)),
+                '*' => starts.push((Op::Mul, i)),
+                ' ' => (),
+                _ => return Err(source.unexpected(ops, i)),
+            }
+        }
+
+        // Each operator starts a column, which runs up to the space before the next
+        let width = rows
+            .iter()
+            .chain([&ops])
+            .map(|row| row.len())
+            .max()
+            .unwrap_or(0);
+        let ends = starts
+            .iter()
+            .skip(1)
+            .map(|&(_, start)| start - 1)
+            .chain([width]);
+        let problems = starts
+            .iter()
+            .zip(ends)
+            .map(|(&(op, start), end)| (op, start, end))
+            .collect::<Vec<_>>();
+
+        if problems.is_empty() {
+            return Err(source.error(ops, "expected an operator"));
+        }
+
+        Ok(Worksheet { rows, problems })
+    }
+}
+
+#[aoc(day6, part1)]
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    let source = Source::new(input);
+    let worksheet = Worksheet::parse(&source, input)?;
+    let mut slots = vec![Vec::with_capacity(worksheet.rows.len()); worksheet.problems.len()];
+
+    for row in &worksheet.rows {
+        let mut numbers = row.split(' ').filter(|s| !s.is_empty());
+
+        for slot in &mut slots {
+            let number = numbers.next().ok_or_else(|| {
+                source.error(
+                    row,
+                    format!("expected {} numbers", worksheet.problems.len()),
+                )
+            })?;
+
+            slot.push(source.parse::<usize>(number)?);
+        }
+
+        if let Some(extra) = numbers.next() {
+            return Err(source.error(extra, "no operator for this number"));
+        }
+    }
+
+    Ok(worksheet
+        .problems
+        .iter()
+        .zip(slots)
+        .map(|(&(op, _, _), slot)| op.apply(slot.into_iter()))
+        .sum())
+}
+
+#[aoc(day6, part2)]
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    let source = Source::new(input);
+    let worksheet = Worksheet::parse(&source, input)?;
+    let mut total = 0;
+
+    for &(op, start, end) in &worksheet.problems {
+        let mut numbers = Vec::with_capacity(end - start);
+
+        // Numbers are read top to bottom, one per character column
+        for pos in start..end {
+            let mut number = None;
+
+            for row in &worksheet.rows {
+                if row.as_bytes().get(pos).is_none_or(|&byte| byte == b' ') {
+                    continue;
+                }
+
+                if !row.is_char_boundary(pos) {
+                    return Err(source.error(row, "expected digits lined up in columns"));
+                }
+
+                let digit = source.digit(row, pos)? as usize;
+
+                number = Some(number.unwrap_or(0) * 10 + digit);
+            }
+
+            numbers.extend(number);
+        }
+
+        total += op.apply(numbers.into_iter());
+    }
+
+    Ok(total)
+}
+
+#[test]
+fn test_day6_malformed() {
+    let err = part1("1 2\n3 x\n* +").unwrap_err();
+
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "x"));
+
+    let err = part1("1 2 3\n* +").unwrap_err();
+
+    assert_eq!(
+        (err.line, err.column, err.message.as_str()),
+        (1, 5, "no operator for this number")
+    );
+
+    let err = part2("12 3\n4x 5\n*  +").unwrap_err();
+
+    assert_eq!(
+        (err.line, err.column, err.message.as_str()),
+        (2, 2, "expected a digit")
+    );
+
+    let err = part2("1 2\n* -").unwrap_err();
+
+    assert_eq!((err.line, err.column, err.text.as_str()), (2, 3, "-"));
+    assert!(part1("1 2").is_err());
+    assert!(part1("").is_err());
+}
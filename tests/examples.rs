@@ -0,0 +1,3 @@
+//! One test per example under `examples/<year>/dayN/`, generated by the build script.
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));